@@ -1,5 +1,9 @@
 use criterion::{black_box, criterion_group, Criterion};
-use termint::{buffer::Buffer, geometry::Rect};
+use termint::{
+    buffer::Buffer,
+    geometry::Rect,
+    widgets::{StrSpanExtension, Widget},
+};
 
 fn benchmark_merge(c: &mut Criterion) {
     let mut buffer = Buffer::empty(Rect::new(1, 1, 255, 255));
@@ -10,4 +14,27 @@ fn benchmark_merge(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, benchmark_merge);
+fn benchmark_render(c: &mut Criterion) {
+    let mut buffer = Buffer::empty(Rect::new(1, 1, 200, 50));
+    "Lorem ipsum odor amet, consectetuer adipiscing elit. "
+        .repeat(200)
+        .to_span()
+        .render(&mut buffer);
+    let mut prev = buffer.clone();
+    "Fames pretium felis".to_span().render(&mut prev);
+
+    c.bench_function("render_to", |b| {
+        b.iter(|| {
+            let mut out = Vec::new();
+            buffer.render_to(black_box(&mut out)).unwrap();
+        });
+    });
+    c.bench_function("render_diff_to", |b| {
+        b.iter(|| {
+            let mut out = Vec::new();
+            buffer.render_diff_to(black_box(&mut out), &prev).unwrap();
+        });
+    });
+}
+
+criterion_group!(benches, benchmark_merge, benchmark_render);
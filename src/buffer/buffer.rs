@@ -1,15 +1,16 @@
 use std::{
-    io::{stdout, Write},
+    io::{self, stdout, Write},
     ops::{Index, IndexMut},
 };
 
 use crate::{
-    enums::{Color, Cursor, Modifier},
+    enums::{Color, ColorMode, Cursor, Modifier},
     geometry::{Rect, Vec2},
     style::Style,
+    text::{grapheme_width, graphemes},
 };
 
-use super::cell::Cell;
+use super::{cell::Cell, Link};
 
 /// A buffer that stores the result of the widget render method. Every widget
 /// interacts with the buffer, instead of printing to the terminal.
@@ -34,10 +35,19 @@ use super::cell::Cell;
 /// // Renders [`Buffer`], which prints the result to the terminal
 /// buffer.render();
 /// ```
+///
+/// Setters taking position (such as [`Buffer::set_val`] or
+/// [`Buffer::set_str`]) panic when the position is outside of the buffer.
+/// Their `try_` counterparts ([`Buffer::try_set_val`],
+/// [`Buffer::try_set_style`] and [`Buffer::try_set_str`]) do nothing and
+/// return `false` instead, which is useful for computed positions. Functions
+/// taking [`Rect`] (such as [`Buffer::fill_rect`]) clip it to the buffer.
 #[derive(Debug, Clone)]
 pub struct Buffer {
     rect: Rect,
     content: Vec<Cell>,
+    color_mode: ColorMode,
+    cursor: Option<Vec2>,
 }
 
 impl Buffer {
@@ -55,6 +65,8 @@ impl Buffer {
         Self {
             rect,
             content: vec![Cell::default(); area],
+            color_mode: Default::default(),
+            cursor: None,
         }
     }
 
@@ -72,24 +84,95 @@ impl Buffer {
         Self {
             rect,
             content: vec![cell; area],
+            color_mode: Default::default(),
+            cursor: None,
         }
     }
 
-    /// Prints the content of the buffer to standard output
+    /// Prints the content of the buffer to standard output. The whole
+    /// output is collected first and written at once, which reduces the
+    /// number of syscalls and flickering
     pub fn render(&self) {
+        let mut buf = Vec::new();
+        _ = self.render_to(&mut buf);
+        Self::write_stdout(&buf);
+    }
+
+    /// Writes the content of the buffer to the given writer. Writer isn't
+    /// flushed, which allows batching multiple renders
+    pub fn render_to<W>(&self, out: &mut W) -> io::Result<()>
+    where
+        W: Write,
+    {
         let mut id = 0;
         let mut style = (Color::Default, Color::Default, Modifier::empty());
+        let mut link = None;
 
         for y in 0..self.height() {
-            print!("{}", Cursor::Pos(self.x(), self.y() + y));
+            write!(out, "{}", Cursor::Pos(self.x(), self.y() + y))?;
+            // Cell following wide character is covered by it
+            let mut skip = false;
             for _ in 0..self.width() {
-                let child = self.content[id];
-                style = self.render_cell(&child, style);
+                let child = &self.content[id];
                 id += 1;
+                if skip {
+                    skip = false;
+                    continue;
+                }
+
+                style = self.render_cell(out, child, style, &mut link)?;
+                skip = child.width() > 1;
+            }
+        }
+        Self::render_end(out, link)
+    }
+
+    /// Gets the content of the buffer as string with the same ANSI escape
+    /// codes as printed by [`Buffer::render`]
+    pub fn to_ansi_string(&self) -> String {
+        let mut out = Vec::new();
+        _ = self.render_to(&mut out);
+        String::from_utf8_lossy(&out).into_owned()
+    }
+
+    /// Gets the characters of the buffer without any styling, rows are
+    /// separated by newline
+    pub fn to_plain_string(&self) -> String {
+        let mut res = String::new();
+        for (y, row) in self.content.chunks(self.width().max(1)).enumerate() {
+            if y != 0 {
+                res.push('\n');
             }
+
+            // Cell following wide character is covered by it
+            let mut skip = false;
+            for cell in row {
+                if !skip {
+                    res.push(cell.val);
+                    res.push_str(cell.tail.as_str());
+                }
+                skip = !skip && cell.width() > 1;
+            }
+        }
+        res
+    }
+
+    /// Gets positions and [`Cell`]s that differ from the given previous
+    /// buffer, without writing anything. This can be used for rendering
+    /// elsewhere than to the terminal (e.g. sending the changes over socket)
+    ///
+    /// When the buffer sizes differ, all the cells are returned
+    pub fn diff_cells(&self, prev: &Buffer) -> Vec<(Vec2, Cell)> {
+        let cells = self.rect.into_iter().zip(self.content.iter().cloned());
+        if self.rect() != prev.rect() {
+            return cells.collect();
         }
-        print!("\x1b[0m");
-        _ = stdout().flush();
+
+        cells
+            .zip(prev.content.iter())
+            .filter(|((_, cell), prev)| cell != *prev)
+            .map(|(change, _)| change)
+            .collect()
     }
 
     /// Prints buffer characters, that are different then in given
@@ -97,37 +180,48 @@ impl Buffer {
     ///
     /// When the buffer sizes differ, it re-renders the whole buffer
     pub fn render_diff(&self, diff: &Buffer) {
+        let mut buf = Vec::new();
+        _ = self.render_diff_to(&mut buf, diff);
+        Self::write_stdout(&buf);
+    }
+
+    /// Writes buffer characters, that are different then in given buffer, to
+    /// the given writer. Writer isn't flushed, which allows batching
+    /// multiple renders
+    ///
+    /// When the buffer sizes differ, it writes the whole buffer
+    pub fn render_diff_to<W>(
+        &self,
+        out: &mut W,
+        diff: &Buffer,
+    ) -> io::Result<()>
+    where
+        W: Write,
+    {
         // TODO: make it compare the cells on shared positions
         if self.rect() != diff.rect() {
-            self.render();
-            return;
+            return self.render_to(out);
         }
 
-        let mut id = 0;
         let mut style = (Color::Default, Color::Default, Modifier::empty());
-
-        for y in 0..self.height() {
-            let mut prev = false;
-            for x in 0..self.width() {
-                let child = self.content[id];
-                let dchild = diff.content[id];
-
-                id += 1;
-                if child == dchild {
-                    prev = false;
+        let mut link = None;
+        // Position where the terminal cursor is after the last written cell
+        let mut next: Option<Vec2> = None;
+
+        for (pos, cell) in self.diff_cells(diff) {
+            if let Some(next) = next {
+                // Cell following wide character is covered by it
+                if next.y == pos.y && pos.x < next.x {
                     continue;
                 }
-
-                if !prev {
-                    print!("{}", Cursor::Pos(self.x() + x, self.y() + y))
-                }
-                style = self.render_cell(&child, style);
-                prev = true;
             }
+            if next != Some(pos) {
+                write!(out, "{}", Cursor::Pos(pos.x, pos.y))?;
+            }
+            style = self.render_cell(out, &cell, style, &mut link)?;
+            next = Some(Vec2::new(pos.x + cell.width().max(1), pos.y));
         }
-
-        print!("\x1b[0m");
-        _ = stdout().flush();
+        Self::render_end(out, link)
     }
 
     /// Gets subset of the buffer based on given rectangle
@@ -139,7 +233,7 @@ impl Buffer {
         let mut buffer = Buffer::empty(rect);
 
         for pos in rect.into_iter() {
-            buffer.set(self[self.index_of(&pos)], &pos);
+            buffer.set(self[self.index_of(&pos)].clone(), &pos);
         }
         buffer
     }
@@ -161,14 +255,17 @@ impl Buffer {
 
         let mut merged = Buffer::empty(rect);
         for (i, pos) in self.rect().into_iter().enumerate() {
-            merged.set(self.content[i], &pos);
+            merged.set(self.content[i].clone(), &pos);
         }
         for (i, pos) in buffer.rect().into_iter().enumerate() {
-            merged.set(buffer.content[i], &pos);
+            merged.set(buffer.content[i].clone(), &pos);
         }
 
         self.rect = merged.rect;
         self.content = merged.content;
+        if buffer.cursor.is_some() {
+            self.cursor = buffer.cursor;
+        }
     }
 
     /// Moves buffer to given position
@@ -205,7 +302,8 @@ impl Buffer {
 
     /// Prints given string to the [`Buffer`] starting at the given position.
     ///
-    /// Truncates the string if it cannot fit the buffer.
+    /// Truncates the string if it cannot fit the buffer. Wide characters
+    /// take two cells, where the second one is left blank.
     ///
     /// # Panics
     /// Panics if the given position is outside of the buffer
@@ -213,19 +311,14 @@ impl Buffer {
     where
         T: AsRef<str>,
     {
-        let mut id = self.index_of(pos);
-        let left = self.content.len().saturating_sub(id);
-
-        for c in str.as_ref().chars().take(left) {
-            self.content[id] = self.content[id].val(c);
-            id += 1;
-        }
+        self.put_str(str.as_ref(), pos, |cell, c| cell.val(c));
     }
 
     /// Prints given string to the [`Buffer`] with given [`Style`] starting at
     /// the given position.
     ///
-    /// Truncates the string if it cannot fit the buffer.
+    /// Truncates the string if it cannot fit the buffer. Wide characters
+    /// take two cells, where the second one is left blank.
     ///
     /// # Panics
     /// Panics if the given position is outside of the buffer
@@ -234,14 +327,22 @@ impl Buffer {
         T: AsRef<str>,
         S: Into<Style>,
     {
-        let mut id = self.index_of(pos);
-        let left = self.content.len().saturating_sub(id);
-
         let style = style.into();
-        for c in str.as_ref().chars().take(left) {
-            self.content[id] = self.content[id].val(c).style(style);
-            id += 1;
+        self.put_str(str.as_ref(), pos, |cell, c| cell.val(c).style(style));
+    }
+
+    /// Prints given string to the [`Buffer`] starting at the given position,
+    /// same as [`Buffer::set_str`]. Returns `false` and does nothing when the
+    /// position is outside of the buffer
+    pub fn try_set_str<T>(&mut self, str: T, pos: &Vec2) -> bool
+    where
+        T: AsRef<str>,
+    {
+        if self.index_of_opt(pos).is_none() {
+            return false;
         }
+        self.put_str(str.as_ref(), pos, |cell, c| cell.val(c));
+        true
     }
 
     /// Sets value of the [`Cell`] on given position in the buffer
@@ -250,7 +351,17 @@ impl Buffer {
     /// Panics if the given position is outside of the buffer
     pub fn set_val(&mut self, val: char, pos: &Vec2) {
         let id = self.index_of(pos);
-        self.content[id] = self.content[id].val(val);
+        self.content[id] = self.content[id].clone().val(val);
+    }
+
+    /// Sets value of the [`Cell`] on given position in the buffer. Returns
+    /// `false` and does nothing when the position is outside of the buffer
+    pub fn try_set_val(&mut self, val: char, pos: &Vec2) -> bool {
+        let Some(id) = self.index_of_opt(pos) else {
+            return false;
+        };
+        self.content[id] = self.content[id].clone().val(val);
+        true
     }
 
     /// Sets style of the [`Cell`] on given position in the buffer
@@ -259,7 +370,20 @@ impl Buffer {
     /// Panics if the given position is outside of the buffer
     pub fn set_style(&mut self, style: Style, pos: &Vec2) {
         let id = self.index_of(pos);
-        self.content[id] = self.content[id].style(style);
+        self.content[id] = self.content[id].clone().style(style);
+    }
+
+    /// Sets style of the [`Cell`] on given position in the buffer. Returns
+    /// `false` and does nothing when the position is outside of the buffer
+    pub fn try_set_style<S>(&mut self, style: S, pos: &Vec2) -> bool
+    where
+        S: Into<Style>,
+    {
+        let Some(id) = self.index_of_opt(pos) else {
+            return false;
+        };
+        self.content[id] = self.content[id].clone().style(style);
+        true
     }
 
     /// Sets foreground of the [`Cell`] on given position in the buffer
@@ -268,7 +392,7 @@ impl Buffer {
     /// Panics if the given position is outside of the buffer
     pub fn set_fg(&mut self, fg: Color, pos: &Vec2) {
         let id = self.index_of(pos);
-        self.content[id] = self.content[id].fg(fg);
+        self.content[id] = self.content[id].clone().fg(fg);
     }
 
     /// Sets background of the [`Cell`] on given position in the buffer
@@ -277,16 +401,149 @@ impl Buffer {
     /// Panics if the given position is outside of the buffer
     pub fn set_bg(&mut self, bg: Color, pos: &Vec2) {
         let id = self.index_of(pos);
-        self.content[id] = self.content[id].bg(bg);
+        self.content[id] = self.content[id].clone().bg(bg);
+    }
+
+    /// Sets hyperlink of the [`Cell`] on given position in the buffer
+    ///
+    /// # Panics
+    /// Panics if the given position is outside of the buffer
+    pub fn set_link(&mut self, link: Option<Link>, pos: &Vec2) {
+        let id = self.index_of(pos);
+        self.content[id] = self.content[id].clone().link(link);
     }
 
     /// Sets modifier of the [`Cell`] on given position in the buffer
     ///
     /// # Panics
     /// Panics if the given position is outside of the buffer
-    pub fn set_modifier(&mut self, modifier: u8, pos: &Vec2) {
+    pub fn set_modifier(&mut self, modifier: u16, pos: &Vec2) {
         let id = self.index_of(pos);
-        self.content[id] = self.content[id].modifier(modifier);
+        self.content[id] = self.content[id].clone().modifier(modifier);
+    }
+
+    /// Sets [`ColorMode`] used when rendering the [`Buffer`], colors not
+    /// supported by it are converted to the closest supported color
+    pub fn set_color_mode(&mut self, mode: ColorMode) {
+        self.color_mode = mode;
+    }
+
+    /// Sets position where the terminal cursor should be shown after
+    /// rendering (e.g. edit position of the text input), `None` hides it.
+    ///
+    /// Only the last set cursor is used, so when multiple widgets set it
+    /// within one frame, the last rendered one wins. Cursor is shown by the
+    /// [`Term`](crate::term::Term) after rendering the [`Buffer`]
+    pub fn set_cursor<T>(&mut self, cursor: T)
+    where
+        T: Into<Option<Vec2>>,
+    {
+        self.cursor = cursor.into();
+    }
+
+    /// Finds all occurences of the given text in the [`Buffer`] and returns
+    /// their starting positions. Buffer is searched row by row and matches
+    /// can continue on the next row, so wrapped text is found as well.
+    /// Text is compared by grapheme clusters, so wide characters and
+    /// clusters (such as letters with combining marks) are found as well
+    pub fn find(&self, needle: &str) -> Vec<Vec2> {
+        let needle: Vec<&str> = graphemes(needle).collect();
+        if needle.is_empty() {
+            return vec![];
+        }
+
+        // Cell following wide character is covered by it
+        let mut cells = Vec::new();
+        let mut skip = false;
+        for (id, cell) in self.content.iter().enumerate() {
+            if !skip || id % self.width() == 0 {
+                cells.push((id, cell));
+            }
+            skip = !skip && cell.width() > 1;
+        }
+
+        cells
+            .windows(needle.len())
+            .filter(|w| {
+                w.iter().zip(needle.iter()).all(|((_, cell), g)| {
+                    let mut chars = g.chars();
+                    chars.next() == Some(cell.val)
+                        && chars.as_str() == cell.tail.as_str()
+                })
+            })
+            .map(|w| self.pos_of(w[0].0))
+            .collect()
+    }
+
+    /// Sets style of `len` cells starting on each of the given positions
+    /// (e.g. positions returned by [`Buffer::find`]). The `len` is in
+    /// display cells, so the found text length should be obtained using
+    /// [`str_width`](crate::text::str_width), since wide characters take two
+    /// cells. Cells are styled row by row, so the style continues on the
+    /// next row
+    ///
+    /// # Panics
+    /// Panics if any of the given positions is outside of the buffer
+    pub fn highlight<S>(&mut self, positions: &[Vec2], len: usize, style: S)
+    where
+        S: Into<Style>,
+    {
+        let style = style.into();
+        for pos in positions {
+            let id = self.index_of(pos);
+            for cell in self.content.iter_mut().skip(id).take(len) {
+                *cell = cell.clone().style(style);
+            }
+        }
+    }
+
+    /// Fills all the cells in the given [`Rect`] with the given [`Cell`].
+    /// The [`Rect`] is clipped to the buffer, so cells outside of the buffer
+    /// are ignored
+    pub fn fill_rect(&mut self, rect: Rect, cell: Cell) {
+        self.fill_with(rect, |_| cell.clone());
+    }
+
+    /// Sets style of all the cells in the given [`Rect`]. The [`Rect`] is
+    /// clipped to the buffer, so cells outside of the buffer are ignored
+    pub fn fill_style<S>(&mut self, rect: Rect, style: S)
+    where
+        S: Into<Style>,
+    {
+        let style = style.into();
+        self.fill_with(rect, |cell| cell.style(style));
+    }
+
+    /// Draws horizontal line of `len` given characters with given style,
+    /// starting at the given position. The line is clipped to the buffer
+    pub fn draw_hline<S>(&mut self, pos: &Vec2, len: usize, c: char, style: S)
+    where
+        S: Into<Style>,
+    {
+        let style = style.into();
+        let rect = Rect::new(pos.x, pos.y, len, 1);
+        self.fill_with(rect, |cell| cell.val(c).style(style));
+    }
+
+    /// Draws vertical line of `len` given characters with given style,
+    /// starting at the given position. The line is clipped to the buffer
+    pub fn draw_vline<S>(&mut self, pos: &Vec2, len: usize, c: char, style: S)
+    where
+        S: Into<Style>,
+    {
+        let style = style.into();
+        let rect = Rect::new(pos.x, pos.y, 1, len);
+        self.fill_with(rect, |cell| cell.val(c).style(style));
+    }
+
+    /// Gets [`ColorMode`] used when rendering the [`Buffer`]
+    pub fn color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    /// Gets position of the terminal cursor set by [`Buffer::set_cursor`]
+    pub fn cursor(&self) -> Option<Vec2> {
+        self.cursor
     }
 
     /// Gets reference to [`Rect`] of the [`Buffer`]
@@ -388,26 +645,130 @@ impl Buffer {
 }
 
 impl Buffer {
-    /// Renders given cell and returns current style
-    fn render_cell(
+    /// Sets all the cells in the given [`Rect`], clipped to the buffer,
+    /// using the given function
+    fn fill_with<F>(&mut self, rect: Rect, set: F)
+    where
+        F: Fn(Cell) -> Cell,
+    {
+        let rect = self.rect.intersection(&rect);
+        if rect.is_empty() {
+            return;
+        }
+
+        for pos in rect.into_iter() {
+            let id = self.index_of(&pos);
+            self.content[id] = set(self.content[id].clone());
+        }
+    }
+
+    /// Prints given string using the given function to set the cells. String
+    /// is split into grapheme clusters, each of them takes one cell (or two
+    /// for wide clusters), wide clusters aren't split between two rows.
+    /// Cluster starting with zero width character is added to the cluster of
+    /// the previous cell, or skipped when there is none
+    fn put_str<F>(&mut self, str: &str, pos: &Vec2, set: F)
+    where
+        F: Fn(Cell, char) -> Cell,
+    {
+        let mut id = self.index_of(pos);
+        let mut last: Option<usize> = None;
+        for grapheme in graphemes(str) {
+            let mut chars = grapheme.chars();
+            let Some(c) = chars.next() else {
+                continue;
+            };
+
+            let width = grapheme_width(grapheme);
+            if width == 0 {
+                if let Some(cell) = last.and_then(|l| self.content.get_mut(l))
+                {
+                    grapheme.chars().for_each(|c| _ = cell.tail.push(c));
+                }
+                continue;
+            }
+
+            if width > 1
+                && self.width() > 1
+                && (id + 1).is_multiple_of(self.width())
+            {
+                if let Some(cell) = self.content.get_mut(id) {
+                    *cell = set(cell.clone(), ' ');
+                }
+                id += 1;
+            }
+
+            let Some(cell) = self.content.get_mut(id) else {
+                break;
+            };
+            *cell = set(cell.clone(), c);
+            chars.for_each(|c| _ = cell.tail.push(c));
+            last = Some(id);
+            if let Some(cell) = self.content.get_mut(id + 1) {
+                if width > 1 {
+                    *cell = set(cell.clone(), ' ');
+                }
+            }
+            id += width;
+        }
+    }
+
+    /// Renders given cell and returns current style. Hyperlink is started
+    /// only when it differs from the currently opened one, so consecutive
+    /// cells with the same link are grouped. Colors are converted to the
+    /// colors supported by the [`ColorMode`] of the [`Buffer`].
+    fn render_cell<W>(
         &self,
+        out: &mut W,
         cell: &Cell,
         mut style: (Color, Color, Modifier),
-    ) -> (Color, Color, Modifier) {
+        link: &mut Option<Link>,
+    ) -> io::Result<(Color, Color, Modifier)>
+    where
+        W: Write,
+    {
+        if cell.link != *link {
+            if link.is_some() {
+                write!(out, "{}", Link::end())?;
+            }
+            if let Some(l) = &cell.link {
+                write!(out, "{}", l.start())?;
+            }
+            link.clone_from(&cell.link);
+        }
         if cell.modifier != style.2 {
             style = (Color::Default, Color::Default, cell.modifier);
-            print!("\x1b[0m{}", cell.modifier);
+            write!(out, "\x1b[0m{}", cell.modifier)?;
+        }
+        let fg = cell.fg.downgrade(self.color_mode);
+        if fg != style.0 {
+            style.0 = fg;
+            write!(out, "{}", fg.to_fg())?;
         }
-        if cell.fg != style.0 {
-            style.0 = cell.fg;
-            print!("{}", cell.fg.to_fg());
+        let bg = cell.bg.downgrade(self.color_mode);
+        if bg != style.1 {
+            style.1 = bg;
+            write!(out, "{}", bg.to_bg())?;
         }
-        if cell.bg != style.1 {
-            style.1 = cell.bg;
-            print!("{}", cell.bg.to_bg());
+        write!(out, "{}{}", cell.val, cell.tail)?;
+        Ok(style)
+    }
+
+    /// Writes given bytes to the standard output at once and flushes it
+    fn write_stdout(buf: &[u8]) {
+        let mut out = stdout().lock();
+        _ = out.write_all(buf).and_then(|_| out.flush());
+    }
+
+    /// Ends the rendering - closes opened hyperlink and resets the style
+    fn render_end<W>(out: &mut W, link: Option<Link>) -> io::Result<()>
+    where
+        W: Write,
+    {
+        if link.is_some() {
+            write!(out, "{}", Link::end())?;
         }
-        print!("{}", cell.val);
-        style
+        write!(out, "\x1b[0m")
     }
 }
 
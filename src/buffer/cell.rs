@@ -3,15 +3,25 @@ use std::fmt::Display;
 use crate::{
     enums::{Color, Modifier},
     style::Style,
+    text::cluster_width,
 };
 
-/// A buffer cell containing foreground, background, modifiers and symbol.
-#[derive(Debug, Clone, Copy, PartialEq)]
+use super::{GraphemeTail, Link};
+
+/// A buffer cell containing foreground, background, modifiers, symbol and
+/// optional hyperlink.
+///
+/// Symbol is the `val` character, followed by the rest of its grapheme
+/// cluster stored in `tail` (e.g. combining accent), see
+/// [`Cell::with_grapheme`].
+#[derive(Debug, Clone, PartialEq)]
 pub struct Cell {
     pub fg: Color,
     pub bg: Color,
     pub modifier: Modifier,
     pub val: char,
+    pub tail: GraphemeTail,
+    pub link: Option<Link>,
 }
 
 impl Cell {
@@ -23,14 +33,36 @@ impl Cell {
         }
     }
 
+    /// Creates new [`Cell`] containing the given grapheme cluster. Its first
+    /// character is the value and the rest is stored in the `tail`
+    pub fn with_grapheme(grapheme: &str) -> Self {
+        let mut chars = grapheme.chars();
+        let Some(val) = chars.next() else {
+            return Self::default();
+        };
+
+        Self {
+            val,
+            tail: GraphemeTail::new(chars.as_str()),
+            ..Default::default()
+        }
+    }
+
     /// Creates empty [`Cell`]
     pub fn empty() -> Self {
         Self::default()
     }
 
-    /// Sets value of the [`Cell`]
+    /// Gets number of cells the grapheme cluster of the [`Cell`] takes when
+    /// displayed in the terminal
+    pub fn width(&self) -> usize {
+        cluster_width(self.val, self.tail.as_str().chars().next())
+    }
+
+    /// Sets value of the [`Cell`], replacing the whole grapheme cluster
     pub fn val(mut self, val: char) -> Self {
         self.val = val;
+        self.tail.clear();
         self
     }
 
@@ -47,12 +79,21 @@ impl Cell {
     }
 
     /// Sets [`Cell`] modifier to the given flag
-    pub fn modifier(mut self, flag: u8) -> Self {
+    pub fn modifier(mut self, flag: u16) -> Self {
         self.modifier.clear();
         self.modifier.add(flag);
         self
     }
 
+    /// Sets hyperlink of the [`Cell`] to the given value
+    pub fn link<T>(mut self, link: T) -> Self
+    where
+        T: Into<Option<Link>>,
+    {
+        self.link = link.into();
+        self
+    }
+
     /// Sets style of the [`Cell`] to the given value. If `fg` or `bg` are
     /// none, it keeps the original value.
     pub fn style<T>(mut self, style: T) -> Self
@@ -70,12 +111,20 @@ impl Cell {
         self
     }
 
+    /// Gets the symbol of the [`Cell`], which is the value followed by the
+    /// rest of its grapheme cluster
+    pub fn symbol(&self) -> String {
+        format!("{}{}", self.val, self.tail)
+    }
+
     /// Resets the [`Cell`] to defalt values
     pub fn reset(&mut self) {
         self.fg = Color::Default;
         self.bg = Color::Default;
         self.modifier = Modifier::empty();
         self.val = ' ';
+        self.tail.clear();
+        self.link = None;
     }
 }
 
@@ -83,11 +132,12 @@ impl Display for Cell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}{}{}{}",
+            "{}{}{}{}{}",
             self.modifier,
             self.fg.to_fg(),
             self.bg.to_bg(),
-            self.val
+            self.val,
+            self.tail
         )
     }
 }
@@ -99,6 +149,8 @@ impl Default for Cell {
             bg: Color::Default,
             modifier: Modifier::empty(),
             val: ' ',
+            tail: GraphemeTail::default(),
+            link: None,
         }
     }
 }
@@ -0,0 +1,82 @@
+use std::fmt::{Debug, Display};
+
+/// Characters following the base character of the [`Cell`](super::Cell) in
+/// a grapheme cluster, such as combining marks, variation selectors and zero
+/// width joiner sequences (e.g. `e` followed by combining acute accent)
+///
+/// The characters are stored inline, so the cell stays cheap to clone.
+/// Characters that don't fit are dropped. The capacity fits the longest
+/// commonly used clusters, such as family of four emoji (21 bytes after the
+/// first character) or subdivision flags made of tag characters (24 bytes).
+/// It adds 25 bytes to the cell, which is less than each of its colors.
+///
+/// ```rust
+/// # use termint::buffer::Cell;
+/// let cell = Cell::with_grapheme("e\u{0301}");
+/// assert_eq!(cell.val, 'e');
+/// assert_eq!(cell.tail.as_str(), "\u{0301}");
+/// assert_eq!(cell.symbol(), "e\u{0301}");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub struct GraphemeTail {
+    bytes: [u8; Self::CAPACITY],
+    len: u8,
+}
+
+impl GraphemeTail {
+    /// Maximum number of bytes the [`GraphemeTail`] can store
+    pub const CAPACITY: usize = 24;
+
+    /// Creates new [`GraphemeTail`] from the given string, characters that
+    /// don't fit are dropped
+    pub fn new(tail: &str) -> Self {
+        let mut res = Self::default();
+        for c in tail.chars() {
+            if !res.push(c) {
+                break;
+            }
+        }
+        res
+    }
+
+    /// Appends given character. Returns `false` and does nothing when there
+    /// isn't enough space for it
+    pub fn push(&mut self, c: char) -> bool {
+        let len = self.len as usize;
+        if len + c.len_utf8() > Self::CAPACITY {
+            return false;
+        }
+
+        c.encode_utf8(&mut self.bytes[len..]);
+        self.len += c.len_utf8() as u8;
+        true
+    }
+
+    /// Removes all the characters
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Gets the characters as string slice
+    pub fn as_str(&self) -> &str {
+        // Only whole characters are ever pushed, so bytes are valid UTF-8
+        std::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or("")
+    }
+
+    /// Checks whether there are no characters
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Debug for GraphemeTail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for GraphemeTail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,41 @@
+use std::rc::Rc;
+
+/// Hyperlink (OSC 8), which can be stored in the [`Cell`](super::Cell)
+///
+/// The URL is shared by reference counting, so cloning the [`Link`] (and
+/// the cells containing it) is cheap and the URL is freed once no cell uses
+/// it. Terminals without hyperlink support render the text normally.
+///
+/// ```rust
+/// # use termint::buffer::{Cell, Link};
+/// let link = Link::new("https://github.com/Martan03/termint");
+/// let cell = Cell::new('t').link(link.clone());
+/// assert_eq!(link.url(), "https://github.com/Martan03/termint");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link(Rc<str>);
+
+impl Link {
+    /// Creates new [`Link`] pointing to the given URL
+    pub fn new<T>(url: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        Self(Rc::from(url.as_ref()))
+    }
+
+    /// Gets URL of the [`Link`]
+    pub fn url(&self) -> &str {
+        &self.0
+    }
+
+    /// Gets ANSI code that starts the hyperlink
+    pub fn start(&self) -> String {
+        format!("\x1b]8;;{}\x1b\\", self.url())
+    }
+
+    /// Gets ANSI code that ends the hyperlink
+    pub fn end() -> &'static str {
+        "\x1b]8;;\x1b\\"
+    }
+}
@@ -3,8 +3,16 @@
 mod buffer;
 /// A buffer cell
 mod cell;
+/// Rest of the grapheme cluster stored in the buffer cell
+mod grapheme;
+/// Hyperlink that can be stored in the buffer cell
+mod link;
 
 /// A buffer that stores the result of the widget render method
 pub use buffer::Buffer;
 /// A buffer cell
 pub use cell::Cell;
+/// Rest of the grapheme cluster stored in the buffer cell
+pub use grapheme::GraphemeTail;
+/// Hyperlink that can be stored in the buffer cell
+pub use link::Link;
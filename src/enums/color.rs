@@ -1,7 +1,32 @@
-use crate::enums::rgb::RGB;
+use crate::enums::{rgb::RGB, ColorMode};
+
+/// Named colors with their RGB values (xterm defaults), used for converting
+/// colors to the 16 color mode
+const NAMED: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (205, 0, 0)),
+    (Color::DarkGreen, (0, 205, 0)),
+    (Color::DarkYellow, (205, 205, 0)),
+    (Color::DarkBlue, (0, 0, 238)),
+    (Color::DarkMagenta, (205, 0, 205)),
+    (Color::DarkCyan, (0, 205, 205)),
+    (Color::LightGray, (229, 229, 229)),
+    (Color::Gray, (127, 127, 127)),
+    (Color::Red, (255, 0, 0)),
+    (Color::Green, (0, 255, 0)),
+    (Color::Yellow, (255, 255, 0)),
+    (Color::Blue, (92, 92, 255)),
+    (Color::Magenta, (255, 0, 255)),
+    (Color::Cyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Levels of each channel in the 6x6x6 color cube of the 256 colors
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
 
 /// ANSI colors
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Color {
     /// Black (fg: 30, bg: 40)
     Black,
@@ -115,9 +140,81 @@ impl Color {
         }
     }
 
+    /// Converts RGB, HSL and Hex [`Color`] to the closest [`Color::Indexed`]
+    /// from the 6x6x6 color cube or grayscale ramp. Other colors are kept
+    pub fn to_ansi256(&self) -> Color {
+        let Some(rgb) = self.rgb() else {
+            return *self;
+        };
+        let id = (16..=255)
+            .min_by_key(|id| Self::distance(rgb, Self::indexed_rgb(*id)))
+            .unwrap_or(16);
+        Color::Indexed(id)
+    }
+
+    /// Converts RGB, HSL, Hex and Indexed [`Color`] to the closest named
+    /// color. Other colors are kept
+    pub fn to_ansi16(&self) -> Color {
+        let rgb = match self {
+            Color::Indexed(id) if (*id as usize) < NAMED.len() => {
+                return NAMED[*id as usize].0;
+            }
+            Color::Indexed(id) => Self::indexed_rgb(*id),
+            _ => match self.rgb() {
+                Some(rgb) => rgb,
+                None => return *self,
+            },
+        };
+        NAMED
+            .iter()
+            .min_by_key(|(_, c)| Self::distance(rgb, (*c).into()))
+            .map_or(*self, |(color, _)| *color)
+    }
+
+    /// Converts [`Color`] to the closest color supported by given
+    /// [`ColorMode`]
+    pub fn downgrade(&self, mode: ColorMode) -> Color {
+        match mode {
+            ColorMode::TrueColor => *self,
+            ColorMode::Ansi256 => self.to_ansi256(),
+            ColorMode::Ansi16 => self.to_ansi16(),
+        }
+    }
+
+    /// Gets [`RGB`] value of the RGB, HSL and Hex [`Color`]
+    fn rgb(&self) -> Option<RGB> {
+        match self {
+            Color::Rgb(r, g, b) => Some(RGB::new(*r, *g, *b)),
+            Color::Hsl(h, s, l) => Some(RGB::from_hsl(*h, *s, *l)),
+            Color::Hex(hex) => Some(RGB::from_hex(*hex)),
+            _ => None,
+        }
+    }
+
+    /// Gets [`RGB`] value of the 256 color with given index
+    fn indexed_rgb(id: u8) -> RGB {
+        match id {
+            0..=15 => NAMED[id as usize].1.into(),
+            16..=231 => {
+                let id = (id - 16) as usize;
+                RGB::new(CUBE[id / 36], CUBE[id / 6 % 6], CUBE[id % 6])
+            }
+            _ => {
+                let val = 8 + (id - 232) * 10;
+                RGB::new(val, val, val)
+            }
+        }
+    }
+
+    /// Gets squared euclidean distance of the given colors
+    fn distance(a: RGB, b: RGB) -> u32 {
+        let diff = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+        diff(a.r, b.r) + diff(a.g, b.g) + diff(a.b, b.b)
+    }
+
     fn str_to_hex(value: &str) -> Option<u32> {
         let value = value.trim_start_matches('#');
-        let Ok(radix) = u32::from_str_radix(&value, 16) else {
+        let Ok(radix) = u32::from_str_radix(value, 16) else {
             return None;
         };
 
@@ -164,7 +261,32 @@ impl From<&str> for Color {
     /// # Panics
     /// Panics if the string is unknown color
     fn from(value: &str) -> Self {
-        match value {
+        match Self::parse(value) {
+            Some(color) => color,
+            None if value.starts_with('#') => {
+                panic!("invalid hex color provided")
+            }
+            None => panic!("unknown color"),
+        }
+    }
+}
+
+impl Color {
+    /// Parses [`Color`] from its name (such as `red` or `dark_blue`), its
+    /// short name (such as `r` or `db`) or hex string (such as `#ff00ff`).
+    ///
+    /// Returns `None` if the color is unknown, unlike the conversion from
+    /// `&str`, which panics.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use termint::enums::Color;
+    /// assert_eq!(Color::parse("dark_blue"), Some(Color::DarkBlue));
+    /// assert_eq!(Color::parse("#ff00ff"), Some(Color::Hex(0xff00ff)));
+    /// assert_eq!(Color::parse("purple"), None);
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        let color = match value {
             "black" | "bl" => Self::Black,
             "dark_red" | "dr" => Self::DarkRed,
             "dark_green" | "dg" => Self::DarkGreen,
@@ -182,13 +304,9 @@ impl From<&str> for Color {
             "cyan" | "c" => Self::Cyan,
             "white" | "w" => Self::White,
             "default" | "d" => Self::Default,
-            hex if hex.starts_with('#') => {
-                let Some(hex) = Self::str_to_hex(hex) else {
-                    panic!("invalid hex color provided");
-                };
-                Self::Hex(hex)
-            }
-            _ => panic!("unknown color"),
-        }
+            hex if hex.starts_with('#') => Self::Hex(Self::str_to_hex(hex)?),
+            _ => return None,
+        };
+        Some(color)
     }
 }
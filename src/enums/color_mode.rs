@@ -0,0 +1,12 @@
+/// Color support of the terminal, colors not supported by the terminal are
+/// converted to the closest supported color when rendering
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ColorMode {
+    /// All colors are supported (24-bit RGB)
+    #[default]
+    TrueColor,
+    /// 8-bit 256 colors
+    Ansi256,
+    /// Basic 16 named colors
+    Ansi16,
+}
@@ -21,6 +21,10 @@ pub enum Cursor {
     PrevBeg(usize),
     /// Moves cursor to column given by given number
     Col(usize),
+    /// Shows the cursor
+    Show,
+    /// Hides the cursor
+    Hide,
 }
 
 impl fmt::Display for Cursor {
@@ -36,6 +40,8 @@ impl fmt::Display for Cursor {
             Cursor::NextBeg(n) => write!(f, "\x1b[{n}E"),
             Cursor::PrevBeg(n) => write!(f, "\x1b[{n}F"),
             Cursor::Col(n) => write!(f, "\x1b[{n}G"),
+            Cursor::Show => write!(f, "\x1b[?25h"),
+            Cursor::Hide => write!(f, "\x1b[?25l"),
         }
     }
 }
@@ -0,0 +1,13 @@
+/// Indicates where the text is truncated when it doesn't fit, which is where
+/// the ellipsis is shown
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum EllipsisMode {
+    /// Keeps the start of the text and truncates its end
+    #[default]
+    End,
+    /// Keeps the end of the text and truncates its start
+    Start,
+    /// Keeps both the start and the end of the text and truncates the middle
+    /// (useful for file paths)
+    Middle,
+}
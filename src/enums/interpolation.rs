@@ -0,0 +1,10 @@
+/// Color space used for interpolating gradient colors
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Interpolation {
+    /// Interpolates each RGB channel linearly
+    #[default]
+    Rgb,
+    /// Interpolates hue (along the shorter arc), saturation and lightness,
+    /// which gives more vivid transitions between distant hues
+    Hsl,
+}
@@ -1,7 +1,13 @@
 /// ANSI colors
 mod color;
+/// Color support of the terminal
+mod color_mode;
 /// ANSI cursor manipulation
 mod cursor;
+/// Position of the ellipsis in truncated text
+mod ellipsis_mode;
+/// Color space used for interpolating gradient colors
+mod interpolation;
 /// ANSI modifiers bitflags
 mod modifier;
 /// Struct representing RGB color
@@ -11,10 +17,18 @@ mod wrap;
 
 /// ANSI colors
 pub use color::Color;
+/// Color support of the terminal
+pub use color_mode::ColorMode;
 /// ANSI cursor manipulation
 pub use cursor::Cursor;
+/// Position of the ellipsis in truncated text
+pub use ellipsis_mode::EllipsisMode;
+/// Color space used for interpolating gradient colors
+pub use interpolation::Interpolation;
 /// ANSI modifiers bitflags
 pub use modifier::Modifier;
+/// Iterator over the set modifier flags
+pub use modifier::ModifierIter;
 /// Struct representing RGB color
 pub use rgb::RGB;
 /// Indicates how text should be wrapped
@@ -8,7 +8,7 @@ use std::fmt;
 /// ```rust
 /// # use termint::{enums::Modifier, modifiers};
 /// // Combines using binary or
-/// let modifiers: u8 = Modifier::BOLD | Modifier::ITALIC;
+/// let modifiers: u16 = Modifier::BOLD | Modifier::ITALIC;
 ///
 /// // Combines using the Modifier struct
 /// let mut modifiers: Modifier = Modifier::empty();
@@ -16,28 +16,43 @@ use std::fmt;
 /// modifiers.add(Modifier::ITALIC);
 ///
 /// // Uses macro (does the same as binary or in shorter way)
-/// let modifiers: u8 = modifiers!(BOLD, ITALIC);
+/// let modifiers: u16 = modifiers!(BOLD, ITALIC);
 /// ```
 #[derive(Debug, PartialEq, Clone, Copy)]
-pub struct Modifier(u8);
+pub struct Modifier(u16);
 
 impl Modifier {
     /// Bold mode
-    pub const BOLD: u8 = 0b0000_0001;
+    pub const BOLD: u16 = 0b0000_0000_0001;
     // Dim/faint mode
-    pub const DIM: u8 = 0b0000_0010;
+    pub const DIM: u16 = 0b0000_0000_0010;
     // Italic mode
-    pub const ITALIC: u8 = 0b0000_0100;
+    pub const ITALIC: u16 = 0b0000_0000_0100;
     // Underline mode
-    pub const UNDERLINED: u8 = 0b0000_1000;
+    pub const UNDERLINED: u16 = 0b0000_0000_1000;
     // Blinking mode
-    pub const BLINK: u8 = 0b0001_0000;
+    pub const BLINK: u16 = 0b0000_0001_0000;
     // Inverse/reverse mode
-    pub const INVERSED: u8 = 0b0010_0000;
+    pub const INVERSED: u16 = 0b0000_0010_0000;
     // Hidden/invisible mode
-    pub const HIDDEN: u8 = 0b0100_0000;
+    pub const HIDDEN: u16 = 0b0000_0100_0000;
     // Strikethrough mode
-    pub const STRIKED: u8 = 0b1000_0000;
+    pub const STRIKED: u16 = 0b0000_1000_0000;
+    // Double underline mode
+    pub const DOUBLE_UNDERLINED: u16 = 0b0001_0000_0000;
+
+    /// Names of the flags, used for example for serialization
+    pub const NAMES: [(u16, &'static str); 9] = [
+        (Self::BOLD, "bold"),
+        (Self::DIM, "dim"),
+        (Self::ITALIC, "italic"),
+        (Self::UNDERLINED, "underlined"),
+        (Self::BLINK, "blink"),
+        (Self::INVERSED, "inversed"),
+        (Self::HIDDEN, "hidden"),
+        (Self::STRIKED, "striked"),
+        (Self::DOUBLE_UNDERLINED, "double_underlined"),
+    ];
 
     /// Gets empty modifier
     pub fn empty() -> Self {
@@ -50,18 +65,70 @@ impl Modifier {
     }
 
     /// Gets the value of the [`Modifier`]
-    pub fn val(&self) -> u8 {
+    pub fn val(&self) -> u16 {
         self.0
     }
 
     /// Adds given flag to the [`Modifier`]
-    pub fn add(&mut self, flag: u8) {
+    pub fn add(&mut self, flag: u16) {
         self.0 |= flag;
     }
 
     /// Subs given flag from the [`Modifier`]
-    pub fn sub(&mut self, flag: u8) {
+    pub fn sub(&mut self, flag: u16) {
+        self.0 &= !flag;
+    }
+
+    /// Toggles given flag of the [`Modifier`]
+    pub fn toggle(&mut self, flag: u16) {
+        self.0 ^= flag;
+    }
+
+    /// Checks whether [`Modifier`] contains all the given flags
+    pub fn contains(&self, flag: u16) -> bool {
+        self.0 & flag == flag
+    }
+
+    /// Checks whether [`Modifier`] has no flags set
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl IntoIterator for Modifier {
+    type Item = u16;
+    type IntoIter = ModifierIter;
+
+    /// Iterates over the set flags, starting with the lowest one
+    fn into_iter(self) -> Self::IntoIter {
+        ModifierIter(self.0)
+    }
+}
+
+/// Iterator over the flags set in [`Modifier`]
+///
+/// ```rust
+/// # use termint::enums::Modifier;
+/// let mut modifier = Modifier::empty();
+/// modifier.add(Modifier::ITALIC | Modifier::BOLD);
+///
+/// let flags: Vec<u16> = modifier.into_iter().collect();
+/// assert_eq!(flags, vec![Modifier::BOLD, Modifier::ITALIC]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ModifierIter(u16);
+
+impl Iterator for ModifierIter {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let flag = self.0 & self.0.wrapping_neg();
         self.0 &= !flag;
+        Some(flag)
     }
 }
 
@@ -94,11 +161,81 @@ impl fmt::Display for Modifier {
         if self.0 & Self::STRIKED != 0 {
             codes.push("9");
         }
+        if self.0 & Self::DOUBLE_UNDERLINED != 0 {
+            codes.push("21");
+        }
 
         if codes.is_empty() {
             Ok(())
         } else {
-            write!(f, "\x1b[1;34;{}m", codes.join(";"))
+            write!(f, "\x1b[{}m", codes.join(";"))
+        }
+    }
+}
+
+/// Serializes [`Modifier`] as a list of the set flag names
+#[cfg(feature = "serde")]
+impl serde::Serialize for Modifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(None)?;
+        for (flag, name) in Self::NAMES {
+            if self.contains(flag) {
+                seq.serialize_element(name)?;
+            }
         }
+        seq.end()
+    }
+}
+
+/// Deserializes [`Modifier`] from a list of flag names or from the raw value
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Modifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ModifierVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ModifierVisitor {
+            type Value = Modifier;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "list of modifier names or u16 value")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                u16::try_from(v).map(Modifier).map_err(|_| {
+                    E::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut modifier = Modifier::empty();
+                while let Some(name) = seq.next_element::<String>()? {
+                    let Some((flag, _)) =
+                        Modifier::NAMES.iter().find(|(_, n)| *n == name)
+                    else {
+                        return Err(serde::de::Error::custom(format!(
+                            "unknown modifier `{name}`"
+                        )));
+                    };
+                    modifier.add(*flag);
+                }
+                Ok(modifier)
+            }
+        }
+
+        deserializer.deserialize_any(ModifierVisitor)
     }
 }
@@ -35,7 +35,7 @@ impl RGB {
 
         let (r, g, b) = if (0.0..60.0).contains(&h) {
             (c, x, 0.0)
-        } else if (60.0..12.0).contains(&h) {
+        } else if (60.0..120.0).contains(&h) {
             (x, c, 0.0)
         } else if (120.0..180.0).contains(&h) {
             (0.0, c, x)
@@ -54,6 +54,74 @@ impl RGB {
         }
     }
 
+    /// Blends [`RGB`] with the given one. `alpha` is the opacity of the
+    /// given color, so 0 keeps the current color and 1 uses the given one
+    pub fn blend(&self, other: RGB, alpha: f64) -> Self {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| {
+            (a as f64 + (b as f64 - a as f64) * alpha).round() as u8
+        };
+        Self {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+        }
+    }
+
+    /// Blends [`RGB`] with the given one in the HSL color space, so the hue
+    /// goes along the shorter arc. Hue of the achromatic color (such as gray)
+    /// is ignored and the hue of the other color is used
+    pub fn blend_hsl(&self, other: RGB, alpha: f64) -> Self {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let (h1, s1, l1) = self.to_hsl();
+        let (h2, s2, l2) = other.to_hsl();
+        let (h1, h2) = if s1 == 0.0 {
+            (h2, h2)
+        } else if s2 == 0.0 {
+            (h1, h1)
+        } else {
+            (h1, h2)
+        };
+
+        let mut diff = h2 - h1;
+        if diff > 180.0 {
+            diff -= 360.0;
+        } else if diff < -180.0 {
+            diff += 360.0;
+        }
+        Self::from_hsl(
+            (h1 + diff * alpha).rem_euclid(360.0),
+            s1 + (s2 - s1) * alpha,
+            l1 + (l2 - l1) * alpha,
+        )
+    }
+
+    /// Converts [`RGB`] to HSL, hue is in degrees, saturation and lightness
+    /// are from 0 to 1
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let r = self.r as f64 / 255.0;
+        let g = self.g as f64 / 255.0;
+        let b = self.b as f64 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let l = (max + min) / 2.0;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (h, s, l)
+    }
+
     /// Divides [`RGB`] by given number
     pub fn div_by(&mut self, num: u8) {
         self.r /= num;
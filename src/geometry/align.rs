@@ -0,0 +1,22 @@
+/// Alignment of the widget within the available space
+#[derive(Default, Debug, PartialEq, Copy, Clone)]
+pub enum Align {
+    /// Aligns to the start (left or top)
+    #[default]
+    Start,
+    /// Centers the widget
+    Center,
+    /// Aligns to the end (right or bottom)
+    End,
+}
+
+impl Align {
+    /// Gets offset of the widget with given size within the given space
+    pub fn offset(&self, size: usize, space: usize) -> usize {
+        match self {
+            Align::Start => 0,
+            Align::Center => space.saturating_sub(size) / 2,
+            Align::End => space.saturating_sub(size),
+        }
+    }
+}
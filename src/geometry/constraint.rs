@@ -1,17 +1,25 @@
 use std::ops::{Range, RangeFrom, RangeTo};
 
 /// Size constraints
+///
+/// For a widget with content size 3:
+/// - `Length(2)` is 2 and `Length(5)` is 5 (content is ignored)
+/// - `Min(2)` is 3 and `Min(5)` is 5 (at least given size, grows to content)
+/// - `Max(2)` is 2 and `Max(5)` is 3 (content size, but at most given size)
+/// - `MinMax(4, 5)` is 4 (content size clamped to the given range)
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Constraint {
-    /// Actual size
+    /// Exact size, content size is ignored
     Length(usize),
     /// Percentage size of the parent widget
     Percent(usize),
     /// Minimum size of the widget, widget expands to fit content
     Min(usize),
-    /// Maximum size of the widget, widget expands to fit content
+    /// Maximum size of the widget, widget takes size of its content up to
+    /// the given size
     Max(usize),
-    /// Minimum and maximum size of the widget, widget expands to fit content
+    /// Minimum and maximum size of the widget, widget takes size of its
+    /// content clamped to the given range
     MinMax(usize, usize),
     /// Fills rest of the space (space is divided by all widgets with fill)
     Fill(usize),
@@ -0,0 +1,21 @@
+use super::Direction;
+
+/// Gradient direction enum
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum GradDirection {
+    #[default]
+    Vertical,
+    Horizontal,
+    /// Gradient going from the top left corner to the bottom right corner,
+    /// color of each cell depends on the sum of its coordinates
+    Diagonal,
+}
+
+impl From<Direction> for GradDirection {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Vertical => Self::Vertical,
+            Direction::Horizontal => Self::Horizontal,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+/// Distribution of the free space between the children along the primary
+/// axis of the [`Layout`](crate::widgets::Layout)
+#[derive(Default, Debug, PartialEq, Copy, Clone)]
+pub enum Justify {
+    /// Children are placed at the start
+    #[default]
+    Start,
+    /// Children are centered
+    Center,
+    /// Free space is divided between the children, first and last child
+    /// are at the edges. Single child is centered
+    SpaceBetween,
+    /// Each child has the same space around it, so the space at the edges
+    /// is half of the space between the children
+    SpaceAround,
+    /// Space between the children and at the edges is the same
+    SpaceEvenly,
+}
@@ -0,0 +1,99 @@
+use super::Vec2;
+
+/// Gets positions of the line between given points using Bresenham's
+/// algorithm. Positions are relative to the area with given size and the
+/// line is first clipped to the area using the Liang-Barsky algorithm, so the
+/// points can lie far outside of it.
+///
+/// # Example
+/// ```rust
+/// # use termint::geometry::{line_points, Vec2};
+/// let line = line_points((-2.0, 0.0), (1e12, 0.0), &Vec2::new(3, 2));
+/// assert_eq!(line, vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(2, 0)]);
+/// ```
+pub fn line_points(
+    start: (f64, f64),
+    end: (f64, f64),
+    size: &Vec2,
+) -> Vec<Vec2> {
+    let Some((start, end)) = clip(start, end, size) else {
+        return vec![];
+    };
+
+    let (mut x, mut y) = start;
+    let dx = (end.0 - x).abs();
+    let dy = -(end.1 - y).abs();
+    let sx = if x < end.0 { 1 } else { -1 };
+    let sy = if y < end.1 { 1 } else { -1 };
+
+    let mut points = vec![];
+    let mut err = dx + dy;
+    loop {
+        points.push(Vec2::new(x as usize, y as usize));
+        if (x, y) == end {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+    points
+}
+
+/// Clips line between given points to the area with given size. Returns the
+/// rounded ends of the clipped line or `None` when the line is completely
+/// outside of the area
+fn clip(
+    start: (f64, f64),
+    end: (f64, f64),
+    size: &Vec2,
+) -> Option<((i64, i64), (i64, i64))> {
+    if size.x == 0 || size.y == 0 {
+        return None;
+    }
+
+    let max = ((size.x - 1) as f64, (size.y - 1) as f64);
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    if !dx.is_finite() || !dy.is_finite() {
+        return None;
+    }
+
+    let (mut t0, mut t1) = (0_f64, 1_f64);
+    let edges = [
+        (-dx, start.0),
+        (dx, max.0 - start.0),
+        (-dy, start.1),
+        (dy, max.1 - start.1),
+    ];
+    for (p, q) in edges {
+        if p == 0. {
+            if q < 0. {
+                return None;
+            }
+            continue;
+        }
+
+        let t = q / p;
+        if p < 0. {
+            t0 = t0.max(t);
+        } else {
+            t1 = t1.min(t);
+        }
+        if t0 > t1 {
+            return None;
+        }
+    }
+
+    let point = |t: f64| {
+        let x = (start.0 + t * dx).round().clamp(0., max.0);
+        let y = (start.1 + t * dy).round().clamp(0., max.1);
+        (x as i64, y as i64)
+    };
+    Some((point(t0), point(t1)))
+}
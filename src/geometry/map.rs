@@ -0,0 +1,57 @@
+use super::{Rect, Vec2};
+
+/// Maps point with logical coordinates `x` and `y` to the cell within given
+/// [`Rect`]. The `bounds` are `(xmin, xmax, ymin, ymax)` and the y axis goes
+/// upwards, so `ymax` maps to the top row of the [`Rect`].
+///
+/// Returns `None` when the point is outside of the bounds, when the bounds
+/// are empty or when the [`Rect`] is empty.
+///
+/// # Example
+/// ```rust
+/// # use termint::geometry::{map_point, Rect, Vec2};
+/// let rect = Rect::new(2, 1, 11, 5);
+/// let bounds = (0.0, 10.0, 0.0, 4.0);
+///
+/// assert_eq!(map_point(&rect, 0.0, 0.0, bounds), Some(Vec2::new(2, 5)));
+/// assert_eq!(map_point(&rect, 5.0, 2.0, bounds), Some(Vec2::new(7, 3)));
+/// assert_eq!(map_point(&rect, 11.0, 2.0, bounds), None);
+/// ```
+pub fn map_point(
+    rect: &Rect,
+    x: f64,
+    y: f64,
+    (xmin, xmax, ymin, ymax): (f64, f64, f64, f64),
+) -> Option<Vec2> {
+    if rect.is_empty()
+        || !(xmin..=xmax).contains(&x)
+        || !(ymin..=ymax).contains(&y)
+    {
+        return None;
+    }
+
+    let (col, row) = project(rect.size(), x, y, (xmin, xmax, ymin, ymax))?;
+    Some(Vec2::new(
+        rect.x() + col.round() as usize,
+        rect.y() + row.round() as usize,
+    ))
+}
+
+/// Projects point with logical coordinates `x` and `y` to the area with
+/// given size, same as [`map_point`], but without rounding and the result
+/// can be outside of the area when the point is outside of the bounds.
+/// Returns `None` when the bounds are empty or the result isn't finite.
+pub(crate) fn project(
+    size: &Vec2,
+    x: f64,
+    y: f64,
+    (xmin, xmax, ymin, ymax): (f64, f64, f64, f64),
+) -> Option<(f64, f64)> {
+    if xmax <= xmin || ymax <= ymin {
+        return None;
+    }
+
+    let col = (x - xmin) / (xmax - xmin) * size.x.saturating_sub(1) as f64;
+    let row = (ymax - y) / (ymax - ymin) * size.y.saturating_sub(1) as f64;
+    (col.is_finite() && row.is_finite()).then_some((col, row))
+}
@@ -1,7 +1,17 @@
+/// Alignment options
+mod align;
 /// Size constraints
 mod constraint;
 /// Direction enum
 mod direction;
+/// Gradient direction enum
+mod grad_direction;
+/// Free space distribution options
+mod justify;
+/// Drawing lines between points
+mod line;
+/// Mapping of logical coordinates to cells
+mod map;
 /// Defines padding struct
 mod padding;
 /// A rectangular area containing its position and size
@@ -15,10 +25,21 @@ mod vec2;
 /// A range bounded by Vec2 inclusively below and exclusively above
 mod vec2_range;
 
+/// Alignment options
+pub use align::Align;
 /// Size constraints
 pub use constraint::Constraint;
 /// Direction enum
 pub use direction::Direction;
+/// Gradient direction enum
+pub use grad_direction::GradDirection;
+/// Free space distribution options
+pub use justify::Justify;
+/// Drawing lines between points
+pub use line::line_points;
+/// Mapping of logical coordinates to cells
+pub use map::map_point;
+pub(crate) use map::project;
 /// Defines padding struct
 pub use padding::Padding;
 /// A rectangular area containing its position and size
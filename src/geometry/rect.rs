@@ -48,6 +48,25 @@ impl Rect {
         }
     }
 
+    /// Gets a new [`Rect`] grown by given margin on all sides. Position is
+    /// clamped at 0, so the [`Rect`] doesn't grow over the origin.
+    pub fn expand(self, n: usize) -> Self {
+        let pos = self.pos.saturating_sub((n, n));
+        Self {
+            pos,
+            size: Vec2::new(
+                self.width() + (self.x() - pos.x) + n,
+                self.height() + (self.y() - pos.y) + n,
+            ),
+        }
+    }
+
+    /// Gets a new [`Rect`] shrunk by given margin on all sides, same as
+    /// `inner` with uniform padding
+    pub fn shrink(self, n: usize) -> Self {
+        self.inner(n)
+    }
+
     /// Creates a new [`Rect`] that contains both current and given
     pub fn union(self, other: &Self) -> Self {
         let min_x = min(self.x(), other.x());
@@ -172,10 +191,15 @@ impl Rect {
             && pos.y <= self.bottom()
     }
 
-    /// Returns true if current [`Rect`] intersects the given one
+    /// Returns true if current [`Rect`] intersects the given one, meaning
+    /// they share at least one cell. Empty [`Rect`] never intersects.
     pub fn intersects(&self, other: &Self) -> bool {
-        (self.x() < other.right() && self.right() > other.x())
-            || (self.y() < other.bottom() && self.bottom() > other.x())
+        !self.is_empty()
+            && !other.is_empty()
+            && self.x() <= other.right()
+            && other.x() <= self.right()
+            && self.y() <= other.bottom()
+            && other.y() <= self.bottom()
     }
 
     /// Gets area of the [`Rect`]
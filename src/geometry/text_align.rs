@@ -5,4 +5,8 @@ pub enum TextAlign {
     Left,
     Center,
     Right,
+    /// Distributes extra spaces between words, so that both margins are
+    /// flush. Last line of the paragraph and lines with single word are
+    /// aligned left. Widgets not supporting it align left instead.
+    Justify,
 }
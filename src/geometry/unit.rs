@@ -3,7 +3,7 @@
 /// [`Unit`] is currently used for the [`Grid`] widget, since it doesn't
 /// support some of the [`Constraint`] options. This might be changed in the
 /// future.
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Unit {
     /// Actual size
     Length(usize),
@@ -11,6 +11,12 @@ pub enum Unit {
     Percent(usize),
     /// Fills rest of the space (space is divided by all widgets with fill)
     Fill(usize),
+    /// Size relative to the perpendicular track with the same index (or the
+    /// last one, when there's not enough of them), multiplied by the ratio.
+    /// When any column uses it, rows are resolved first and columns are
+    /// sized based on them, otherwise columns are resolved first. Using it
+    /// in both rows and columns isn't supported (rows resolve to 0 then).
+    Aspect(f64),
 }
 
 impl From<usize> for Unit {
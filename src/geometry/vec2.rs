@@ -1,4 +1,5 @@
 use std::{
+    cmp::Ordering,
     fmt::Display,
     ops::{
         Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub,
@@ -86,6 +87,23 @@ where
     }
 }
 
+impl<T> Vec2<T>
+where
+    T: PartialOrd,
+{
+    /// Returns true if both coordinates are less than or equal to the
+    /// coordinates of `other`
+    pub fn componentwise_le(&self, other: &Self) -> bool {
+        self.x <= other.x && self.y <= other.y
+    }
+
+    /// Returns true if both coordinates are strictly less than the
+    /// coordinates of `other`
+    pub fn componentwise_lt(&self, other: &Self) -> bool {
+        self.x < other.x && self.y < other.y
+    }
+}
+
 impl<T> Vec2<T>
 where
     T: Copy + PartialOrd,
@@ -119,33 +137,24 @@ impl<T> IndexMut<usize> for Vec2<T> {
     }
 }
 
+/// Componentwise (product) partial order. One vector is less or equal to
+/// the other only when both of its coordinates are less or equal, so vectors
+/// such as `(1, 3)` and `(2, 2)` are incomparable and all of `<`, `<=`, `>`
+/// and `>=` return false for them.
+///
+/// Note that `a < b` only requires `a <= b` and `a != b`, so one of the
+/// coordinates can still be equal. Use [`Vec2::componentwise_lt`] when both
+/// coordinates have to be strictly less.
 impl<T> PartialOrd for Vec2<T>
 where
     T: PartialOrd,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.x < other.x && self.y < other.y {
-            Some(std::cmp::Ordering::Less)
-        } else if self.x > other.x && self.y > other.y {
-            Some(std::cmp::Ordering::Greater)
-        } else {
-            None
-        }
-    }
-}
-
-impl<T> Ord for Vec2<T>
-where
-    T: PartialOrd + Ord,
-{
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match (
-            self.x < other.x && self.y < other.y,
-            self.x > other.x && self.y > other.y,
-        ) {
-            (true, false) => std::cmp::Ordering::Less,
-            (false, true) => std::cmp::Ordering::Greater,
-            _ => std::cmp::Ordering::Equal,
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.x.partial_cmp(&other.x)?, self.y.partial_cmp(&other.y)?) {
+            (x, y) if x == y => Some(x),
+            (Ordering::Equal, y) => Some(y),
+            (x, Ordering::Equal) => Some(x),
+            _ => None,
         }
     }
 }
@@ -171,17 +180,26 @@ where
     }
 }
 
+/// Subtracts the coordinates component-wise.
+///
+/// For unsigned types, such as `usize`, this overflows when any component of
+/// `rhs` is greater than the component of `self`, which panics in debug
+/// builds and wraps in release builds. Use [`Vec2::saturating_sub`] or
+/// [`Vec2::checked_sub`] when that can happen.
 impl<L, R> Sub<Vec2<R>> for Vec2<L>
 where
-    L: Add<R>,
+    L: Sub<R>,
 {
     type Output = Vec2<L::Output>;
 
     fn sub(self, rhs: Vec2<R>) -> Self::Output {
-        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
     }
 }
 
+/// Subtracts the coordinates component-wise in place.
+///
+/// Overflows the same way as [`Sub`] does, see its documentation.
 impl<L, R> SubAssign<Vec2<R>> for Vec2<L>
 where
     L: SubAssign<R>,
@@ -23,7 +23,7 @@ where
 
     /// Returns true if item is in the [`Vec2`] range
     pub fn contains(&self, item: &Vec2<T>) -> bool {
-        &self.start <= item && item.x < self.end.x && item.y < self.end.y
+        self.start.componentwise_le(item) && item.componentwise_lt(&self.end)
     }
 }
 
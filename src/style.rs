@@ -4,6 +4,7 @@ use crate::enums::{Color, Modifier};
 
 /// Style struct containing foreground, background and modifiers
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     pub fg: Option<Color>,
     pub bg: Option<Color>,
@@ -42,23 +43,40 @@ impl Style {
     }
 
     /// Sets modifier to the given flag
-    pub fn modifier(mut self, flag: u8) -> Self {
+    pub fn modifier(mut self, flag: u16) -> Self {
         self.modifier.clear();
         self.modifier.add(flag);
         self
     }
 
     /// Adds given modifier to the already set modifiers
-    pub fn add_modifier(mut self, flag: u8) -> Self {
+    pub fn add_modifier(mut self, flag: u16) -> Self {
         self.modifier.add(flag);
         self
     }
 
     /// Removes given modifier from the already set modifiers
-    pub fn remove_modifier(mut self, flag: u8) -> Self {
+    pub fn remove_modifier(mut self, flag: u16) -> Self {
         self.modifier.sub(flag);
         self
     }
+
+    /// Gets [`Style`] with the given style layered over the current one.
+    /// Colors set in the given style take precedence, unset ones fall
+    /// through to the current style and modifiers are combined
+    pub fn merge(&self, over: &Style) -> Style {
+        let mut style = *self;
+        style.patch(over);
+        style
+    }
+
+    /// Applies given [`Style`] on top of the current one in place, the same
+    /// way as [`Style::merge`]
+    pub fn patch(&mut self, over: &Style) {
+        self.fg = over.fg.or(self.fg);
+        self.bg = over.bg.or(self.bg);
+        self.modifier.add(over.modifier.val());
+    }
 }
 
 impl Display for Style {
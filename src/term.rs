@@ -1,6 +1,9 @@
+use std::io::{stdout, Write};
+
 use crate::{
     buffer::Buffer,
-    geometry::{Vec2, Padding, Rect},
+    enums::{ColorMode, Cursor},
+    geometry::{Padding, Rect, Vec2},
     widgets::Widget,
 };
 
@@ -23,12 +26,22 @@ use crate::{
 /// // Term with padding 0 on top, 1 on right, 2 on bottom, 3 on left
 /// term = term.padding((0, 1, 2, 3));
 /// ```
+///
+/// When the cursor was hidden by the [`Term`] (after being shown using
+/// [`Buffer::set_cursor`]), it's shown again when the [`Term`] is dropped.
 #[derive(Debug, Default)]
 pub struct Term {
     prev: Option<Buffer>,
     prev_widget: Option<Box<dyn Widget>>,
     small: Option<Box<dyn Widget>>,
     padding: Padding,
+    frame: Option<Vec<u8>>,
+    record: bool,
+    frames: Vec<Buffer>,
+    color_mode: ColorMode,
+    /// Cursor visibility set by the last rendering, `None` when the cursor
+    /// wasn't shown yet
+    cursor: Option<bool>,
 }
 
 impl Term {
@@ -43,6 +56,20 @@ impl Term {
         self
     }
 
+    /// Sets [`ColorMode`] supported by the terminal, colors not supported
+    /// by it are converted to the closest supported color when rendering
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        self.color_mode = mode;
+        self
+    }
+
+    /// Makes [`Term`] record every rendered [`Buffer`], which can be then
+    /// obtained using [`Term::recorded_frames`] (e.g. for demo captures)
+    pub fn record(mut self) -> Self {
+        self.record = true;
+        self
+    }
+
     /// Sets small screen of the [`Term`], which is displayed if rendering
     /// cannot fit
     pub fn small_screen<T>(mut self, small_screen: T) -> Self
@@ -80,12 +107,7 @@ impl Term {
         };
         self.prev_widget = Some(Box::new(widget));
 
-        match &self.prev {
-            Some(prev) => buffer.render_diff(prev),
-            None => buffer.render(),
-        }
-        self.prev = Some(buffer);
-
+        self.output(buffer);
         Ok(())
     }
 
@@ -115,17 +137,110 @@ impl Term {
             _ => widget.render(&mut buffer),
         };
 
-        match &self.prev {
-            Some(prev) => buffer.render_diff(prev),
-            None => buffer.render(),
-        }
-        self.prev = Some(buffer);
-
+        self.output(buffer);
         Ok(())
     }
 
+    /// Begins a frame. All the renders until [`Term::end_frame`] is called
+    /// are collected and printed at once, which prevents tearing when
+    /// rendering multiple times per frame
+    pub fn begin_frame(&mut self) {
+        self.frame.get_or_insert_with(Vec::new);
+    }
+
+    /// Ends the frame started by [`Term::begin_frame`] and prints all the
+    /// collected renders, flushing the output only once
+    pub fn end_frame(&mut self) -> Result<(), &'static str> {
+        let Some(frame) = self.frame.take() else {
+            return Err("Cannot end frame: no frame was begun");
+        };
+
+        let mut out = stdout().lock();
+        out.write_all(&frame)
+            .and_then(|_| out.flush())
+            .map_err(|_| "Cannot print the frame")
+    }
+
+    /// Gets the [`Buffer`] produced by the last rendering, `None` when
+    /// nothing was rendered yet
+    pub fn last_buffer(&self) -> Option<&Buffer> {
+        self.prev.as_ref()
+    }
+
+    /// Gets all the [`Buffer`]s rendered since recording was enabled using
+    /// [`Term::record`]
+    pub fn recorded_frames(&self) -> &[Buffer] {
+        &self.frames
+    }
+
     /// Gets size of the terminal
     pub fn get_size() -> Option<(usize, usize)> {
         term_size::dimensions()
     }
 }
+
+impl Term {
+    /// Prints given buffer, when frame is begun, it's only collected and
+    /// printed when the frame ends
+    fn output(&mut self, mut buffer: Buffer) {
+        buffer.set_color_mode(self.color_mode);
+        // When the terminal was resized, the screen is cleared, otherwise
+        // the content outside of the new area would be left on the screen
+        if self
+            .prev
+            .as_ref()
+            .is_some_and(|p| p.rect() != buffer.rect())
+        {
+            self.prev = None;
+            match &mut self.frame {
+                Some(frame) => frame.extend_from_slice(b"\x1b[2J"),
+                None => print!("\x1b[2J"),
+            }
+        }
+        match (&mut self.frame, &self.prev) {
+            (Some(frame), Some(prev)) => {
+                _ = buffer.render_diff_to(frame, prev);
+            }
+            (Some(frame), None) => _ = buffer.render_to(frame),
+            (None, Some(prev)) => buffer.render_diff(prev),
+            (None, None) => buffer.render(),
+        }
+        self.output_cursor(buffer.cursor());
+        if self.record {
+            self.frames.push(buffer.clone());
+        }
+        self.prev = Some(buffer);
+    }
+
+    /// Moves the cursor to the given position and shows it, hides the
+    /// cursor when the position is `None`. Visibility is changed only when
+    /// it differs from the last rendering.
+    fn output_cursor(&mut self, cursor: Option<Vec2>) {
+        let code = match (cursor, self.cursor) {
+            (Some(pos), Some(true)) => Cursor::Pos(pos.x, pos.y).to_string(),
+            (Some(pos), _) => {
+                format!("{}{}", Cursor::Pos(pos.x, pos.y), Cursor::Show)
+            }
+            (None, Some(true)) => Cursor::Hide.to_string(),
+            (None, _) => return,
+        };
+        self.cursor = Some(cursor.is_some());
+        match &mut self.frame {
+            Some(frame) => frame.extend_from_slice(code.as_bytes()),
+            None => {
+                let mut out = stdout().lock();
+                _ = out.write_all(code.as_bytes()).and_then(|_| out.flush());
+            }
+        }
+    }
+}
+
+impl Drop for Term {
+    /// Shows the cursor again when it was hidden by the [`Term`]
+    fn drop(&mut self) {
+        if self.cursor == Some(false) {
+            let mut out = stdout().lock();
+            _ = write!(out, "{}", Cursor::Show).and_then(|_| out.flush());
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::{OnceLock, RwLock};
+
+/// Ellipsis used by text widgets, when no other is set
+static ELLIPSIS: OnceLock<RwLock<String>> = OnceLock::new();
+
+/// Gets the default ellipsis used by the text widgets (such as [`Span`] or
+/// [`Grad`]) when created. It's "..." unless changed using
+/// [`set_default_ellipsis`].
+///
+/// [`Span`]: crate::widgets::Span
+/// [`Grad`]: crate::widgets::Grad
+pub fn default_ellipsis() -> String {
+    ellipsis()
+        .read()
+        .map_or_else(|_| "...".to_string(), |e| e.clone())
+}
+
+/// Sets the default ellipsis used by the text widgets created after this
+/// call. Single character ellipsis (such as "…") is better for narrow areas.
+///
+/// ```rust
+/// # use termint::{text::{default_ellipsis, set_default_ellipsis}};
+/// set_default_ellipsis("…");
+/// assert_eq!(default_ellipsis(), "…");
+/// ```
+pub fn set_default_ellipsis<T>(ellipsis: T)
+where
+    T: AsRef<str>,
+{
+    if let Ok(mut e) = self::ellipsis().write() {
+        *e = ellipsis.as_ref().to_string();
+    }
+}
+
+/// Gets the default ellipsis lock
+fn ellipsis() -> &'static RwLock<String> {
+    ELLIPSIS.get_or_init(|| RwLock::new("...".to_string()))
+}
@@ -0,0 +1,95 @@
+use crate::{
+    enums::{Color, Modifier, Wrap},
+    style::Style,
+    widgets::{Paragraph, Span},
+};
+
+/// Parses markup string into [`Paragraph`] of styled [`Span`]s
+///
+/// Tags are written in square brackets and contain space separated color
+/// names (see [`Color::parse`]), background colors prefixed with `on_` and
+/// modifier names (see [`Modifier::NAMES`]). Tags can be nested, `[/]`
+/// closes the lastly opened tag and `[[` is a literal bracket. Unknown tags
+/// are kept as literal text.
+///
+/// Whitespace is kept exactly as written, so the styled parts are placed
+/// right after each other and the [`Paragraph`] uses letter wrapping.
+///
+/// ## Example usage:
+/// ```rust
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     geometry::Rect,
+/// #     text::markup,
+/// #     widgets::Widget,
+/// # };
+/// let legend = markup("[red bold]Error:[/] file [italic]missing[/]");
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 20, 1));
+/// legend.render(&mut buffer);
+/// assert_eq!(buffer.to_plain_string(), "Error: file missing ");
+/// ```
+pub fn markup(text: &str) -> Paragraph {
+    let mut paragraph = Paragraph::empty().separator("").wrap(Wrap::Letter);
+    let mut styles = vec![Style::new()];
+    let mut part = String::new();
+
+    let mut rest = text;
+    while let Some(id) = rest.find('[') {
+        part.push_str(&rest[..id]);
+        rest = &rest[id + 1..];
+        if let Some(escaped) = rest.strip_prefix('[') {
+            part.push('[');
+            rest = escaped;
+            continue;
+        }
+
+        let tag = rest.find(']').map(|end| (&rest[..end], &rest[end + 1..]));
+        let style = styles[styles.len() - 1];
+        match tag {
+            Some(("/", next)) if styles.len() > 1 => {
+                push_part(&mut paragraph, &mut part, style);
+                styles.pop();
+                rest = next;
+            }
+            Some((tag, next)) => match parse_tag(tag) {
+                Some(tag) => {
+                    push_part(&mut paragraph, &mut part, style);
+                    styles.push(style.merge(&tag));
+                    rest = next;
+                }
+                None => part.push('['),
+            },
+            None => part.push('['),
+        }
+    }
+    part.push_str(rest);
+    push_part(&mut paragraph, &mut part, styles[styles.len() - 1]);
+    paragraph
+}
+
+/// Adds the text part with given style to the paragraph and clears it
+fn push_part(paragraph: &mut Paragraph, part: &mut String, style: Style) {
+    if !part.is_empty() {
+        paragraph.add(Span::new(part.as_str()).style(style));
+    }
+    part.clear();
+}
+
+/// Parses the tag content to [`Style`], `None` if any of the names is
+/// unknown
+fn parse_tag(tag: &str) -> Option<Style> {
+    let mut style = Style::new();
+    for name in tag.split_whitespace() {
+        if let Some(bg) = name.strip_prefix("on_") {
+            style = style.bg(Color::parse(bg)?);
+        } else if let Some(color) = Color::parse(name) {
+            style = style.fg(color);
+        } else {
+            let (flag, _) =
+                Modifier::NAMES.iter().find(|(_, n)| *n == name)?;
+            style = style.add_modifier(*flag);
+        }
+    }
+    (!tag.trim().is_empty()).then_some(style)
+}
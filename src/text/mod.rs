@@ -1,8 +1,19 @@
 mod text_trait;
 pub use text_trait::Text;
 
+mod ellipsis;
+pub use ellipsis::{default_ellipsis, set_default_ellipsis};
+
+mod width;
+pub use width::{char_width, str_width};
+pub(crate) use width::{
+    cluster_width, grapheme_width, graphemes, prefix, suffix,
+};
+
+mod markup;
+pub use markup::markup;
+
 mod parser;
 pub(crate) use parser::TextParser;
 
 mod text_token;
-pub(crate) use text_token::TextToken;
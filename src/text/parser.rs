@@ -1,10 +1,14 @@
-use super::text_token::TextToken;
+use super::{
+    text_token::TextToken,
+    width::{char_width, str_width},
+};
 
 /// Parses the text so it can be rendered more easily
 pub struct TextParser<'a> {
     text: &'a mut dyn Iterator<Item = char>,
     cur: Option<char>,
     last: TextToken,
+    word_break: bool,
 }
 
 impl<'a> TextParser<'a> {
@@ -15,41 +19,73 @@ impl<'a> TextParser<'a> {
             text,
             cur,
             last: TextToken::End,
+            word_break: false,
         }
     }
 
-    /// Gets next line from the text
-    pub fn next_line(&mut self, max_len: usize) -> TextToken {
-        let (mut words, mut line_len) = match &self.last {
-            TextToken::Text { text, len } => (vec![text.clone()], *len),
-            _ => (vec![], 0),
+    /// Sets whether words longer than the line are split into multiple
+    /// lines, otherwise they overflow the line
+    pub fn word_break(mut self, word_break: bool) -> Self {
+        self.word_break = word_break;
+        self
+    }
+
+    /// Gets words of the next line from the text and the line length
+    /// (including single spaces between words)
+    pub fn next_line_words(
+        &mut self,
+        max_len: usize,
+    ) -> Option<(Vec<String>, usize)> {
+        let (mut words, mut line_len) = (vec![], 0);
+        let mut token = match self.last {
+            TextToken::Text { .. } => {
+                std::mem::replace(&mut self.last, TextToken::End)
+            }
+            _ => self.next_word(),
         };
-        // TODO: handle when word cannot fit
-        self.last = TextToken::End;
 
         loop {
-            match self.next_word() {
+            match token {
                 TextToken::Text { text, len } => {
                     let space = (line_len != 0) as usize;
-                    if line_len + len + space > max_len {
+                    if line_len + len + space <= max_len {
+                        words.push(text);
+                        line_len += len + space;
+                    } else if line_len != 0 {
                         self.last = TextToken::text(text, len);
                         break;
+                    } else if self.word_break {
+                        let (head, rest) = Self::split(text, max_len);
+                        line_len = str_width(&head);
+                        words.push(head);
+                        if !rest.is_empty() {
+                            self.last = TextToken::text(rest, len - line_len);
+                        }
+                        break;
+                    } else {
+                        words.push(text);
+                        line_len = len;
+                        break;
                     }
-
-                    words.push(text);
-                    line_len += len + space;
                 }
-                TextToken::Newline => return TextToken::Newline,
+                TextToken::Newline => return Some((words, line_len)),
                 _ => break,
             }
+            token = self.next_word();
         }
 
         match line_len {
-            0 => TextToken::End,
-            _ => TextToken::text(words.join(" "), line_len),
+            0 => None,
+            _ => Some((words, line_len)),
         }
     }
 
+    /// Checks whether the last line was wrapped because of its length, which
+    /// means it isn't the last line of the paragraph
+    pub fn wrapped(&self) -> bool {
+        matches!(self.last, TextToken::Text { .. })
+    }
+
     /// Reads next word in the text, skips leading whitespaces
     pub fn next_word(&mut self) -> TextToken {
         if !self.skip_whitespace() {
@@ -65,7 +101,7 @@ impl<'a> TextParser<'a> {
             }
 
             word.push(c);
-            word_len += 1;
+            word_len += char_width(c);
             self.cur = self.text.next();
         }
 
@@ -75,6 +111,21 @@ impl<'a> TextParser<'a> {
         }
     }
 
+    /// Splits the word after the longest prefix fitting into the given width,
+    /// the prefix always contains at least one character
+    fn split(mut word: String, max_len: usize) -> (String, String) {
+        let mut len = 0;
+        let id = word
+            .char_indices()
+            .find(|(i, c)| {
+                len += char_width(*c);
+                *i != 0 && len > max_len
+            })
+            .map_or(word.len(), |(i, _)| i);
+        let rest = word.split_off(id);
+        (word, rest)
+    }
+
     /// Skips whitespace characters except newline.
     /// Returns true when no newline, else false
     fn skip_whitespace(&mut self) -> bool {
@@ -94,10 +145,14 @@ impl<'a> TextParser<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::text::TextToken;
+    use crate::text::text_token::TextToken;
 
     use super::TextParser;
 
+    fn line(words: &[&str], len: usize) -> Option<(Vec<String>, usize)> {
+        Some((words.iter().map(|w| w.to_string()).collect(), len))
+    }
+
     #[test]
     fn test_next_word() {
         let text = String::from("This    is  \n   a test");
@@ -119,14 +174,28 @@ mod tests {
         let mut parser = TextParser::new(&mut text_iter);
 
         assert_eq!(
-            parser.next_line(14),
-            TextToken::text("This is a test".into(), 14)
+            parser.next_line_words(14),
+            line(&["This", "is", "a", "test"], 14)
         );
         assert_eq!(
-            parser.next_line(14),
-            TextToken::text("of next line".into(), 12)
+            parser.next_line_words(14),
+            line(&["of", "next", "line"], 12)
         );
-        assert_eq!(parser.next_line(14), TextToken::End);
+        assert_eq!(parser.next_line_words(14), None);
+    }
+
+    #[test]
+    fn test_next_line_words() {
+        let text = String::from("This is  a test\nof words");
+        let mut text_iter = text.chars();
+        let mut parser = TextParser::new(&mut text_iter);
+
+        assert_eq!(parser.next_line_words(8), line(&["This", "is"], 7));
+        assert!(parser.wrapped());
+        assert_eq!(parser.next_line_words(8), line(&["a", "test"], 6));
+        assert!(!parser.wrapped());
+        assert_eq!(parser.next_line_words(8), line(&["of", "words"], 8));
+        assert_eq!(parser.next_line_words(8), None);
     }
 
     #[test]
@@ -135,10 +204,59 @@ mod tests {
         let mut text_iter = text.chars();
         let mut parser = TextParser::new(&mut text_iter);
 
-        assert_eq!(parser.next_line(14), TextToken::text("This is".into(), 7));
-        assert_eq!(parser.next_line(14), TextToken::text("a".into(), 1));
-        assert_eq!(parser.next_line(14), TextToken::text("".into(), 0));
-        assert_eq!(parser.next_line(14), TextToken::text("test".into(), 4));
-        assert_eq!(parser.next_line(14), TextToken::End);
+        assert_eq!(parser.next_line_words(14), line(&["This", "is"], 7));
+        assert_eq!(parser.next_line_words(14), line(&["a"], 1));
+        assert_eq!(parser.next_line_words(14), line(&[], 0));
+        assert_eq!(parser.next_line_words(14), line(&["test"], 4));
+        assert_eq!(parser.next_line_words(14), None);
+    }
+
+    #[test]
+    fn test_next_line_word_break() {
+        let text = String::from("supercalifragilistic");
+        let mut text_iter = text.chars();
+        let mut parser = TextParser::new(&mut text_iter).word_break(true);
+
+        assert_eq!(parser.next_line_words(5), line(&["super"], 5));
+        assert_eq!(parser.next_line_words(5), line(&["calif"], 5));
+        assert_eq!(parser.next_line_words(5), line(&["ragil"], 5));
+        assert_eq!(parser.next_line_words(5), line(&["istic"], 5));
+        assert_eq!(parser.next_line_words(5), None);
+
+        let text = String::from("ab 中文字 c");
+        let mut text_iter = text.chars();
+        let mut parser = TextParser::new(&mut text_iter).word_break(true);
+
+        assert_eq!(parser.next_line_words(3), line(&["ab"], 2));
+        assert_eq!(parser.next_line_words(3), line(&["中"], 2));
+        assert_eq!(parser.next_line_words(3), line(&["文"], 2));
+        assert_eq!(parser.next_line_words(3), line(&["字"], 2));
+        assert_eq!(parser.next_line_words(3), line(&["c"], 1));
+    }
+
+    #[test]
+    fn test_next_line_overflow() {
+        let text = String::from("a supercalifragilistic b");
+        let mut text_iter = text.chars();
+        let mut parser = TextParser::new(&mut text_iter);
+
+        assert_eq!(parser.next_line_words(5), line(&["a"], 1));
+        assert_eq!(
+            parser.next_line_words(5),
+            line(&["supercalifragilistic"], 20)
+        );
+        assert_eq!(parser.next_line_words(5), line(&["b"], 1));
+        assert_eq!(parser.next_line_words(5), None);
+    }
+
+    #[test]
+    fn test_next_line_wide() {
+        let text = String::from("中文 ab 字");
+        let mut text_iter = text.chars();
+        let mut parser = TextParser::new(&mut text_iter);
+
+        assert_eq!(parser.next_line_words(7), line(&["中文", "ab"], 7));
+        assert_eq!(parser.next_line_words(7), line(&["字"], 2));
+        assert_eq!(parser.next_line_words(7), None);
     }
 }
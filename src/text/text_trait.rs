@@ -2,6 +2,8 @@ use core::fmt;
 
 use crate::{buffer::Buffer, enums::Wrap, geometry::Vec2};
 
+use super::{default_ellipsis, str_width};
+
 /// Trait for text widgets to implement
 /// Makes work with more [`Text`] widgets easier
 pub trait Text {
@@ -20,8 +22,23 @@ pub trait Text {
     /// Gets text of the [`Text`]
     fn get_text(&self) -> &str;
 
+    /// Gets number of cells the text of the [`Text`] takes when displayed,
+    /// which respects wide characters (unlike the byte length of the text)
+    fn display_width(&self) -> usize {
+        str_width(self.get_text())
+    }
+
     /// Gets [`Text`] ansi codes (fg, bg, mods) in String
     fn get_mods(&self) -> String;
+
+    /// Gets x coordinate where the line ends when using word wrap and the
+    /// line contains word longer than the `width`. The line starts at
+    /// `start` and words before the long word take `len` cells. The rest of
+    /// the line is replaced with ellipsis.
+    fn ellipsis_end(&self, start: usize, len: usize, width: usize) -> usize {
+        let ellipsis = str_width(&default_ellipsis());
+        start + ellipsis + len.min(width.saturating_sub(ellipsis + start))
+    }
 }
 
 impl fmt::Debug for dyn Text {
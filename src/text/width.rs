@@ -0,0 +1,236 @@
+/// Ranges of characters, that take two cells when displayed (East Asian
+/// wide and fullwidth characters and emoji)
+const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x2329, 0x232A),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x2E80, 0x303E),
+    (0x3041, 0x33FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF),
+    (0xA960, 0xA97F),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE6F),
+    (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x16FE0, 0x16FE4),
+    (0x17000, 0x18CFF),
+    (0x1B000, 0x1B2FF),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F200, 0x1F251),
+    (0x1F300, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F900, 0x1F9FF),
+    (0x1FA70, 0x1FAFF),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];
+
+/// Ranges of characters, that take no cells when displayed (combining marks,
+/// zero width characters, tags and variation selectors)
+const ZERO: &[(u32, u32)] = &[
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x0610, 0x061A),
+    (0x064B, 0x065F),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x2028, 0x202E),
+    (0x2060, 0x2064),
+    (0x20D0, 0x20FF),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFEFF, 0xFEFF),
+    (0xE0020, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+/// Gets number of cells the given character takes when displayed in the
+/// terminal (0, 1 or 2)
+///
+/// ```rust
+/// # use termint::text::char_width;
+/// assert_eq!(char_width('a'), 1);
+/// assert_eq!(char_width('中'), 2);
+/// assert_eq!(char_width('\u{0301}'), 0);
+/// ```
+pub fn char_width(c: char) -> usize {
+    let c = c as u32;
+    if c < 0x7F {
+        return (c >= 0x20) as usize;
+    }
+    if c < 0xA0 || in_ranges(ZERO, c) {
+        return 0;
+    }
+    1 + in_ranges(WIDE, c) as usize
+}
+
+/// Gets number of cells the given string takes when displayed in the
+/// terminal. Each grapheme cluster (such as emoji with skin tone modifier or
+/// flag made of two regional indicators) is counted once
+///
+/// ```rust
+/// # use termint::text::str_width;
+/// assert_eq!(str_width("a中"), 3);
+/// assert_eq!(str_width("\u{1F44D}\u{1F3FD}"), 2);
+/// assert_eq!(str_width("\u{1F1E8}\u{1F1FF}"), 2);
+/// ```
+pub fn str_width(s: &str) -> usize {
+    graphemes(s).map(grapheme_width).sum()
+}
+
+/// Gets the longest prefix of the text fitting the given width
+pub(crate) fn prefix(text: &str, width: usize) -> &str {
+    let mut len = 0;
+    for (i, c) in text.char_indices() {
+        len += char_width(c);
+        if len > width {
+            return &text[..i];
+        }
+    }
+    text
+}
+
+/// Gets the longest suffix of the text fitting the given width
+pub(crate) fn suffix(text: &str, width: usize) -> &str {
+    let mut len = 0;
+    for (i, c) in text.char_indices().rev() {
+        len += char_width(c);
+        if len > width {
+            return &text[i + c.len_utf8()..];
+        }
+    }
+    text
+}
+
+/// Splits given string into grapheme clusters, which are displayed as a
+/// single character. Cluster is made of the base character followed by
+/// characters with zero width (combining marks, variation selectors, zero
+/// width joiner), emoji modifiers and characters following zero width
+/// joiner. Two regional indicators form a flag.
+///
+/// This covers the clusters commonly used in terminals, but it isn't the
+/// full Unicode segmentation (such as Hangul syllables made of jamo).
+pub(crate) fn graphemes(s: &str) -> Graphemes<'_> {
+    Graphemes { rest: s }
+}
+
+/// Gets number of cells the given grapheme cluster takes when displayed in
+/// the terminal, which is the width of its base character, except for flags
+/// made of two regional indicators, which take two cells
+pub(crate) fn grapheme_width(grapheme: &str) -> usize {
+    let mut chars = grapheme.chars();
+    chars
+        .next()
+        .map_or(0, |base| cluster_width(base, chars.next()))
+}
+
+/// Gets width of the grapheme cluster with given base character and the
+/// character following it
+pub(crate) fn cluster_width(base: char, next: Option<char>) -> usize {
+    match next {
+        Some(next) if is_regional(base) && is_regional(next) => 2,
+        _ => char_width(base),
+    }
+}
+
+/// Iterator over grapheme clusters of a string, see [`graphemes`]
+pub(crate) struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+
+        let mut end = first.len_utf8();
+        let (mut prev, mut regional) = (first, is_regional(first) as usize);
+        for (id, c) in chars {
+            let joins = prev == '\u{200D}'
+                || char_width(c) == 0
+                || is_modifier(c)
+                || (regional == 1 && is_regional(c));
+            if !joins {
+                break;
+            }
+
+            regional += is_regional(c) as usize;
+            prev = c;
+            end = id + c.len_utf8();
+        }
+
+        let (grapheme, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(grapheme)
+    }
+}
+
+/// Checks whether given character is regional indicator, two of them form
+/// a flag
+fn is_regional(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+/// Checks whether given character is emoji modifier (skin tone)
+fn is_modifier(c: char) -> bool {
+    ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+}
+
+/// Checks whether given character is in any of the given sorted ranges
+fn in_ranges(ranges: &[(u32, u32)], c: u32) -> bool {
+    ranges
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
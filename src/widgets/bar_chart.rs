@@ -0,0 +1,212 @@
+use crate::{
+    buffer::Buffer,
+    geometry::Vec2,
+    style::Style,
+    text::{char_width, str_width},
+};
+
+use super::{widget::Widget, Element};
+
+/// Characters used for drawing bars, each one eighth bigger than previous
+pub(crate) const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Bar chart widget drawing vertical bars with labels under them
+///
+/// Bars are scaled to the height of the rendered area, so the biggest value
+/// takes the whole height. Top of each bar uses the eighth-block characters,
+/// which makes the bars more precise.
+///
+/// ## Example usage:
+/// ```rust
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::Rect,
+/// #     widgets::{BarChart, Widget},
+/// # };
+/// let chart = BarChart::new(vec![
+///     ("a.txt".to_string(), 120),
+///     ("b.txt".to_string(), 80),
+///     ("c.txt".to_string(), 200),
+/// ])
+/// .bar_width(5)
+/// .bar_style(Color::Cyan)
+/// .show_values(true);
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 17, 8));
+/// chart.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct BarChart {
+    data: Vec<(String, u64)>,
+    chart_height: usize,
+    bar_width: usize,
+    gap: usize,
+    bar_style: Style,
+    label_style: Style,
+    show_values: bool,
+}
+
+impl BarChart {
+    /// Creates new [`BarChart`] with given labels and values
+    pub fn new(data: Vec<(String, u64)>) -> Self {
+        Self {
+            data,
+            chart_height: 10,
+            bar_width: 1,
+            gap: 1,
+            bar_style: Default::default(),
+            label_style: Default::default(),
+            show_values: false,
+        }
+    }
+
+    /// Sets the requested height of the [`BarChart`] (including labels)
+    pub fn chart_height(mut self, height: usize) -> Self {
+        self.chart_height = height;
+        self
+    }
+
+    /// Sets width of each bar
+    pub fn bar_width(mut self, width: usize) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    /// Sets size of the gap between the bars
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets style of the bars
+    pub fn bar_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.bar_style = style.into();
+        self
+    }
+
+    /// Sets style of the labels and values
+    pub fn label_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Sets whether value of each bar is shown under it
+    pub fn show_values(mut self, show: bool) -> Self {
+        self.show_values = show;
+        self
+    }
+}
+
+impl Widget for BarChart {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 || self.bar_width == 0 {
+            return;
+        }
+
+        let labels = 1 + self.show_values as usize;
+        let rows = buffer.height().saturating_sub(labels);
+        let max = self.data.iter().map(|(_, v)| *v).max().unwrap_or(0);
+
+        let end = buffer.x() + buffer.width();
+        let mut x = buffer.x();
+        for (label, value) in self.data.iter() {
+            if x >= end {
+                break;
+            }
+            let width = self.bar_width.min(end - x);
+
+            if max != 0 {
+                let eighths = *value as u128 * rows as u128 * 8 / max as u128;
+                self.render_bar(buffer, x, width, rows, eighths as usize);
+            }
+
+            let mut y = buffer.y() + rows;
+            if self.show_values && y <= buffer.bottom() {
+                self.render_label(buffer, &value.to_string(), x, y, width);
+                y += 1;
+            }
+            if y <= buffer.bottom() {
+                self.render_label(buffer, label, x, y, width);
+            }
+
+            x += self.bar_width + self.gap;
+        }
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        self.chart_height
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        let bars = self.data.len();
+        bars * self.bar_width + bars.saturating_sub(1) * self.gap
+    }
+}
+
+impl BarChart {
+    /// Renders bar with given height in eighths of the cell
+    fn render_bar(
+        &self,
+        buffer: &mut Buffer,
+        x: usize,
+        width: usize,
+        rows: usize,
+        eighths: usize,
+    ) {
+        for row in 0..rows {
+            let cell = eighths.saturating_sub(row * 8).min(8);
+            if cell == 0 {
+                break;
+            }
+
+            let y = buffer.y() + rows - row - 1;
+            for x in x..x + width {
+                let pos = Vec2::new(x, y);
+                buffer.set_val(BARS[cell - 1], &pos);
+                buffer.set_style(self.bar_style, &pos);
+            }
+        }
+    }
+
+    /// Renders label centered under the bar, truncated to the bar width
+    fn render_label(
+        &self,
+        buffer: &mut Buffer,
+        label: &str,
+        x: usize,
+        y: usize,
+        width: usize,
+    ) {
+        let mut len = 0;
+        let label: String = label
+            .chars()
+            .take_while(|c| {
+                len += char_width(*c);
+                len <= width
+            })
+            .collect();
+        let pos = Vec2::new(x + (width - str_width(&label)) / 2, y);
+        buffer.set_str_styled(label, &pos, self.label_style);
+    }
+}
+
+// From implementations
+impl From<BarChart> for Box<dyn Widget> {
+    fn from(value: BarChart) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<BarChart> for Element {
+    fn from(value: BarChart) -> Self {
+        Element::new(value)
+    }
+}
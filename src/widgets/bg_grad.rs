@@ -1,7 +1,9 @@
 use crate::{
     buffer::Buffer,
     enums::{Color, RGB},
-    geometry::{Constraint, Direction, Padding, Vec2},
+    geometry::{
+        Align, Constraint, Direction, GradDirection, Justify, Padding, Vec2,
+    },
     style::Style,
 };
 
@@ -45,8 +47,9 @@ use super::{widget::Widget, Element, Layout};
 pub struct BgGrad<W = Element> {
     bg_start: RGB,
     bg_end: RGB,
-    direction: Direction,
+    direction: GradDirection,
     padding: Padding,
+    blend: Option<f64>,
     child: W,
 }
 
@@ -63,8 +66,9 @@ where
         Self {
             bg_start: start.into(),
             bg_end: end.into(),
-            direction: Direction::Vertical,
+            direction: GradDirection::Vertical,
             padding: Default::default(),
+            blend: None,
             child,
         }
     }
@@ -78,15 +82,37 @@ where
         Self {
             bg_start: start.into(),
             bg_end: end.into(),
-            direction: Direction::Horizontal,
+            direction: GradDirection::Horizontal,
             padding: Default::default(),
+            blend: None,
             child,
         }
     }
 
-    /// Sets gradient [`Direction`] of the [`BgGrad`]
-    pub fn bg_dir(mut self, direction: Direction) -> Self {
-        self.direction = direction;
+    /// Creates new diagonal [`BgGrad`] with given gradient colors, going
+    /// from the top left to the bottom right corner
+    pub fn diagonal<T1, T2>(child: W, start: T1, end: T2) -> Self
+    where
+        T1: Into<RGB>,
+        T2: Into<RGB>,
+    {
+        Self {
+            bg_start: start.into(),
+            bg_end: end.into(),
+            direction: GradDirection::Diagonal,
+            padding: Default::default(),
+            blend: None,
+            child,
+        }
+    }
+
+    /// Sets gradient direction of the [`BgGrad`], accepts both
+    /// [`Direction`] and [`GradDirection`]
+    pub fn bg_dir<T>(mut self, direction: T) -> Self
+    where
+        T: Into<GradDirection>,
+    {
+        self.direction = direction.into();
         self
     }
 
@@ -98,6 +124,15 @@ where
         self.padding = padding.into();
         self
     }
+
+    /// Makes [`BgGrad`] blend the gradient with the existing background
+    /// instead of replacing it. `alpha` is the opacity of the gradient (from
+    /// 0 to 1). Background which has no known RGB value (such as the default
+    /// or named colors) is replaced by the gradient.
+    pub fn blend(mut self, alpha: f64) -> Self {
+        self.blend = Some(alpha);
+        self
+    }
 }
 
 impl BgGrad<Layout> {
@@ -140,6 +175,19 @@ impl BgGrad<Layout> {
         self
     }
 
+    /// Sets how the free space is distributed between the [`Layout`]
+    /// children
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.child = self.child.justify(justify);
+        self
+    }
+
+    /// Sets alignment of the [`Layout`] children on the cross axis
+    pub fn cross_align(mut self, align: Align) -> Self {
+        self.child = self.child.cross_align(align);
+        self
+    }
+
     /// Adds child with its [`Constraint`] to [`Layout`]
     #[deprecated(
         since = "0.6.0",
@@ -161,6 +209,17 @@ impl BgGrad<Layout> {
     {
         self.child.push(child, constraint);
     }
+
+    /// Adds child with its [`Constraint`] to the [`BgGrad`] and returns it,
+    /// which allows building the [`BgGrad`] in a single expression
+    pub fn child<T, C>(mut self, child: T, constraint: C) -> Self
+    where
+        T: Into<Box<dyn Widget>>,
+        C: Into<Constraint>,
+    {
+        self.push(child, constraint);
+        self
+    }
 }
 
 impl<W> Widget for BgGrad<W>
@@ -173,8 +232,9 @@ where
         }
 
         match self.direction {
-            Direction::Vertical => self.ver_render(buffer),
-            Direction::Horizontal => self.hor_render(buffer),
+            GradDirection::Vertical => self.ver_render(buffer),
+            GradDirection::Horizontal => self.hor_render(buffer),
+            GradDirection::Diagonal => self.diag_render(buffer),
         };
 
         let mut cbuffer = buffer.subset(buffer.rect().inner(self.padding));
@@ -197,6 +257,10 @@ where
         );
         self.child.width(&size) + self.padding.get_horizontal()
     }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![&self.child]
+    }
 }
 
 impl<W> BgGrad<W>
@@ -210,11 +274,11 @@ where
             (self.bg_start.r, self.bg_start.g, self.bg_start.b);
 
         for x in buffer.x()..buffer.width() + buffer.x() {
-            let bg = Color::Rgb(r, g, b);
+            let bg = RGB::new(r, g, b);
             (r, g, b) = self.add_step((r, g, b), step);
 
             for y in buffer.y()..buffer.height() + buffer.y() {
-                buffer.set_bg(bg, &Vec2::new(x, y));
+                self.set_bg(buffer, bg, &Vec2::new(x, y));
             }
         }
     }
@@ -226,15 +290,45 @@ where
             (self.bg_start.r, self.bg_start.g, self.bg_start.b);
 
         for y in buffer.y()..buffer.height() + buffer.y() {
-            let bg = Color::Rgb(r, g, b);
+            let bg = RGB::new(r, g, b);
             (r, g, b) = self.add_step((r, g, b), step);
 
             for x in buffer.x()..buffer.width() + buffer.x() {
-                buffer.set_bg(bg, &Vec2::new(x, y));
+                self.set_bg(buffer, bg, &Vec2::new(x, y));
             }
         }
     }
 
+    /// Renders diagonal background gradient
+    fn diag_render(&self, buffer: &mut Buffer) {
+        let len = buffer.width() + buffer.height() - 2;
+        for pos in buffer.rect().into_iter() {
+            let dist = pos.x - buffer.x() + pos.y - buffer.y();
+            let t = match len {
+                0 => 0.,
+                _ => dist as f64 / len as f64,
+            };
+            self.set_bg(buffer, self.bg_start.blend(self.bg_end, t), &pos);
+        }
+    }
+
+    /// Sets background on given position, blending it with the current
+    /// background when blending is enabled
+    fn set_bg(&self, buffer: &mut Buffer, bg: RGB, pos: &Vec2) {
+        let Some(alpha) = self.blend else {
+            buffer.set_bg(Color::Rgb(bg.r, bg.g, bg.b), pos);
+            return;
+        };
+
+        let bg = match buffer[*pos].bg {
+            Color::Rgb(r, g, b) => RGB::new(r, g, b).blend(bg, alpha),
+            Color::Hsl(h, s, l) => RGB::from_hsl(h, s, l).blend(bg, alpha),
+            Color::Hex(hex) => RGB::from_hex(hex).blend(bg, alpha),
+            _ => bg,
+        };
+        buffer.set_bg(Color::Rgb(bg.r, bg.g, bg.b), pos);
+    }
+
     /// Gets step per character based on start and eng background color
     fn get_step(&self, len: i16) -> (i16, i16, i16) {
         (
@@ -4,7 +4,9 @@ use crate::{
     borders,
     buffer::Buffer,
     enums::Color,
-    geometry::{Constraint, Direction, Padding, Rect, Vec2},
+    geometry::{
+        Align, Constraint, Direction, Justify, Padding, Rect, TextAlign, Vec2,
+    },
     style::Style,
     text::Text,
     widgets::span::Span,
@@ -48,8 +50,12 @@ use super::{
 #[derive(Debug)]
 pub struct Block<W = Element> {
     title: Box<dyn Text>,
+    title_align: TextAlign,
+    side_titles: Option<[Box<dyn Text>; 2]>,
+    bottom_title: Box<dyn Text>,
+    bottom_title_align: TextAlign,
     borders: u8,
-    border_type: BorderType,
+    border_types: [BorderType; 4],
     border_style: Style,
     child: W,
 }
@@ -63,8 +69,12 @@ where
     pub fn new(child: W) -> Self {
         Self {
             title: Box::new(Span::new("")),
+            title_align: Default::default(),
+            side_titles: None,
+            bottom_title: Box::new(Span::new("")),
+            bottom_title_align: Default::default(),
             borders: Border::ALL,
-            border_type: BorderType::Normal,
+            border_types: Default::default(),
             border_style: Default::default(),
             child,
         }
@@ -79,6 +89,46 @@ where
         self
     }
 
+    /// Sets alignment of the [`Block`] title within the top border
+    pub fn title_align(mut self, align: TextAlign) -> Self {
+        self.title_align = align;
+        self
+    }
+
+    /// Sets three titles of the [`Block`] top border, like in a window title
+    /// bar. Left and right titles are aligned to the sides and the center
+    /// title is centered between them.
+    ///
+    /// When the titles don't fit, the left one is shown first, then the
+    /// right one and the center title gets the remaining space.
+    pub fn titles<L, C, R>(mut self, left: L, center: C, right: R) -> Self
+    where
+        L: Into<Box<dyn Text>>,
+        C: Into<Box<dyn Text>>,
+        R: Into<Box<dyn Text>>,
+    {
+        self.side_titles = Some([left.into(), right.into()]);
+        self.title = center.into();
+        self.title_align = TextAlign::Center;
+        self
+    }
+
+    /// Sets [`Text`] as a title rendered on the bottom border of the
+    /// [`Block`]
+    pub fn bottom_title<T>(mut self, title: T) -> Self
+    where
+        T: Into<Box<dyn Text>>,
+    {
+        self.bottom_title = title.into();
+        self
+    }
+
+    /// Sets alignment of the [`Block`] bottom title within the bottom border
+    pub fn bottom_title_align(mut self, align: TextAlign) -> Self {
+        self.bottom_title_align = align;
+        self
+    }
+
     /// Sets which [`Block`] borders should be displayed
     pub fn borders(mut self, borders: u8) -> Self {
         self.borders = borders;
@@ -87,7 +137,20 @@ where
 
     /// Sets type of the border of the [`Block`]
     pub fn border_type(mut self, border_type: BorderType) -> Self {
-        self.border_type = border_type;
+        self.border_types = [border_type; 4];
+        self
+    }
+
+    /// Sets type of each side of the [`Block`] border. Corners join types
+    /// of the adjacent sides (see [`BorderType::corner`])
+    pub fn border_types(
+        mut self,
+        top: BorderType,
+        right: BorderType,
+        bottom: BorderType,
+        left: BorderType,
+    ) -> Self {
+        self.border_types = [top, right, bottom, left];
         self
     }
 
@@ -112,8 +175,12 @@ impl Block<Spacer> {
     pub fn empty() -> Self {
         Self {
             title: Box::new(Span::new("")),
+            title_align: Default::default(),
+            side_titles: None,
+            bottom_title: Box::new(Span::new("")),
+            bottom_title_align: Default::default(),
             borders: Border::ALL,
-            border_type: BorderType::Normal,
+            border_types: Default::default(),
             border_style: Default::default(),
             child: Spacer::new(),
         }
@@ -125,8 +192,12 @@ impl Block<Layout> {
     pub fn vertical() -> Self {
         Self {
             title: Box::new(Span::new("")),
+            title_align: Default::default(),
+            side_titles: None,
+            bottom_title: Box::new(Span::new("")),
+            bottom_title_align: Default::default(),
             borders: Border::ALL,
-            border_type: Default::default(),
+            border_types: Default::default(),
             border_style: Default::default(),
             child: Layout::vertical(),
         }
@@ -136,8 +207,12 @@ impl Block<Layout> {
     pub fn horizontal() -> Self {
         Self {
             title: Box::new(Span::new("")),
+            title_align: Default::default(),
+            side_titles: None,
+            bottom_title: Box::new(Span::new("")),
+            bottom_title_align: Default::default(),
             borders: Border::ALL,
-            border_type: Default::default(),
+            border_types: Default::default(),
             border_style: Default::default(),
             child: Layout::horizontal(),
         }
@@ -191,6 +266,19 @@ impl Block<Layout> {
         self
     }
 
+    /// Sets how the free space is distributed between the [`Layout`]
+    /// children
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.child = self.child.justify(justify);
+        self
+    }
+
+    /// Sets alignment of the [`Layout`] children on the cross axis
+    pub fn cross_align(mut self, align: Align) -> Self {
+        self.child = self.child.cross_align(align);
+        self
+    }
+
     /// Adds child with its [`Constraint`] to [`Layout`]
     #[deprecated(
         since = "0.6.0",
@@ -212,6 +300,17 @@ impl Block<Layout> {
     {
         self.child.push(child, constraint);
     }
+
+    /// Adds child with its [`Constraint`] to the [`Block`] and returns it,
+    /// which allows building the [`Block`] in a single expression
+    pub fn child<T, C>(mut self, child: T, constraint: C) -> Self
+    where
+        T: Into<Box<dyn Widget>>,
+        C: Into<Constraint>,
+    {
+        self.push(child, constraint);
+        self
+    }
 }
 
 impl<W> Widget for Block<W>
@@ -221,15 +320,20 @@ where
     /// Renders [`Block`] with selected borders and title
     fn render(&self, buffer: &mut Buffer) {
         let (t, r, b, l) = self.render_border(buffer);
-        let mut pos = Vec2::new(buffer.x() + l, buffer.y());
-        let mut size = Vec2::new(buffer.width().saturating_sub(l + r), 1);
-
-        let mut tbuffer = buffer.subset(Rect::from_coords(pos, size));
-        _ = self.title.render_offset(&mut tbuffer, 0, None);
-        buffer.merge(tbuffer);
+        let margins = self.render_side_titles(buffer, (l, r));
+        let (title, align) = (&*self.title, self.title_align);
+        self.render_title(buffer, title, align, buffer.top(), margins);
+        if buffer.height() > 1 {
+            let (title, align) =
+                (&*self.bottom_title, self.bottom_title_align);
+            self.render_title(buffer, title, align, buffer.bottom(), (l, r));
+        }
 
-        pos.y += t;
-        size.y = buffer.height().saturating_sub(t + b);
+        let pos = Vec2::new(buffer.x() + l, buffer.y() + t);
+        let size = Vec2::new(
+            buffer.width().saturating_sub(l + r),
+            buffer.height().saturating_sub(t + b),
+        );
         let rect = Rect::from_coords(pos, size);
         if !buffer.rect().contains(&rect) {
             return;
@@ -254,7 +358,18 @@ where
             size.x.saturating_sub(width),
             size.y.saturating_sub(height),
         );
-        max(self.child.width(&size), self.title.get_text().len()) + width
+        let mut title = self.title.display_width();
+        if let Some(sides) = &self.side_titles {
+            for side in sides.iter().map(|s| s.display_width()) {
+                title += side + (title > 0 && side > 0) as usize;
+            }
+        }
+        let title = title.max(self.bottom_title.display_width());
+        max(self.child.width(&size), title) + width
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![&self.child]
     }
 }
 
@@ -285,18 +400,82 @@ where
         (t, r, b, l)
     }
 
+    /// Renders left and right titles on the top border and returns margins
+    /// of the space left for the main title. When the main title is centered
+    /// and fits between the side titles, it is centered within the whole
+    /// border instead
+    fn render_side_titles(
+        &self,
+        buffer: &mut Buffer,
+        (l, r): (usize, usize),
+    ) -> (usize, usize) {
+        let Some([left, right]) = &self.side_titles else {
+            return (l, r);
+        };
+
+        let span = buffer.width().saturating_sub(l + r);
+        let lw = left.display_width().min(span);
+        self.render_title(
+            buffer,
+            &**left,
+            TextAlign::Left,
+            buffer.top(),
+            (l, r),
+        );
+        // Side titles are separated by at least one border character
+        let lm = l + lw + (lw > 0) as usize;
+
+        let span = buffer.width().saturating_sub(lm + r);
+        let rw = right.display_width().min(span);
+        let y = buffer.top();
+        self.render_title(buffer, &**right, TextAlign::Right, y, (lm, r));
+        let rm = r + rw + (rw > 0) as usize;
+
+        let width = self.title.display_width();
+        let start = l + buffer.width().saturating_sub(l + r + width) / 2;
+        let end = buffer.width().saturating_sub(rm);
+        match self.title_align {
+            TextAlign::Center if start >= lm && start + width <= end => (l, r),
+            _ => (lm, rm),
+        }
+    }
+
+    /// Renders given title on the given row, aligned between the borders
+    fn render_title(
+        &self,
+        buffer: &mut Buffer,
+        title: &dyn Text,
+        align: TextAlign,
+        y: usize,
+        (l, r): (usize, usize),
+    ) {
+        let span = buffer.width().saturating_sub(l + r);
+        let width = title.display_width().min(span);
+        if width == 0 {
+            return;
+        }
+
+        let offset = match align {
+            TextAlign::Left | TextAlign::Justify => 0,
+            TextAlign::Center => (span - width) >> 1,
+            TextAlign::Right => span - width,
+        };
+        let pos = Vec2::new(buffer.x() + l + offset, y);
+        let mut tbuffer =
+            buffer.subset(Rect::from_coords(pos, Vec2::new(width, 1)));
+        _ = title.render_offset(&mut tbuffer, 0, None);
+        buffer.merge(tbuffer);
+    }
+
     /// Adds horizontal border to the buffer
     fn hor_border(&self, buffer: &mut Buffer, y: usize, border: u8) -> usize {
         if (self.borders & border) == 0 {
             return 0;
         }
 
-        let c = self.border_type.get(border);
-        let mut pos = Vec2::new(buffer.x(), y);
-        while pos.x <= buffer.right() {
-            buffer[pos] = buffer[pos].val(c).style(self.border_style);
-            pos.x += 1;
-        }
+        let c = self.side_type(border).get(border);
+        let pos = Vec2::new(buffer.x(), y);
+        buffer.draw_hline(&pos, buffer.width(), c, self.border_style);
         1
     }
 
@@ -306,20 +485,29 @@ where
             return 0;
         }
 
-        let c = self.border_type.get(border);
-        let mut pos = Vec2::new(x, buffer.y());
-        while pos.y <= buffer.bottom() {
-            buffer[pos] = buffer[pos].val(c).style(self.border_style);
-            pos.y += 1;
-        }
+        let c = self.side_type(border).get(border);
+        let pos = Vec2::new(x, buffer.y());
+        buffer.draw_vline(&pos, buffer.height(), c, self.border_style);
         1
     }
 
     /// Adds corner of [`Block`] border to the string
     fn render_corner(&self, buffer: &mut Buffer, pos: Vec2, border: u8) {
         if (self.borders & border) == border {
-            let c = self.border_type.get(border);
-            buffer[pos] = buffer[pos].val(c).style(self.border_style);
+            let hor = self.side_type(border & borders!(TOP, BOTTOM));
+            let ver = self.side_type(border & borders!(LEFT, RIGHT));
+            let c = BorderType::corner(hor, ver, border);
+            buffer[pos] = buffer[pos].clone().val(c).style(self.border_style);
+        }
+    }
+
+    /// Gets type of the given border side
+    fn side_type(&self, side: u8) -> &BorderType {
+        match side {
+            Border::TOP => &self.border_types[0],
+            Border::RIGHT => &self.border_types[1],
+            Border::BOTTOM => &self.border_types[2],
+            _ => &self.border_types[3],
         }
     }
 
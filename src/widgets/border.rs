@@ -29,7 +29,7 @@ impl Border {
 }
 
 /// Border type enum
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum BorderType {
     /// Simple line
     #[default]
@@ -44,6 +44,82 @@ pub enum BorderType {
     Double,
     /// Dashed line
     Dash,
+    /// Custom border characters
+    Custom(BorderSet),
+}
+
+/// Set of characters used by [`BorderType::Custom`]
+///
+/// ## Example usage:
+/// ```rust
+/// # use termint::widgets::{Block, BorderSet, BorderType};
+/// // ASCII-only border for terminals without box-drawing characters
+/// let block = Block::vertical()
+///     .border_type(BorderType::Custom(BorderSet::ascii()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderSet {
+    /// Top and bottom side
+    pub top: char,
+    /// Left and right side
+    pub left: char,
+    /// Top left corner
+    pub top_left: char,
+    /// Top right corner
+    pub top_right: char,
+    /// Bottom left corner
+    pub bottom_left: char,
+    /// Bottom right corner
+    pub bottom_right: char,
+    /// Left side joined with a horizontal line (`├`)
+    pub left_tee: char,
+    /// Right side joined with a horizontal line (`┤`)
+    pub right_tee: char,
+    /// Top side joined with a vertical line (`┬`)
+    pub top_tee: char,
+    /// Bottom side joined with a vertical line (`┴`)
+    pub bottom_tee: char,
+    /// Crossing of horizontal and vertical line (`┼`)
+    pub cross: char,
+}
+
+impl BorderSet {
+    /// Creates [`BorderSet`] using only ASCII characters (`-`, `|` and `+`)
+    pub fn ascii() -> Self {
+        Self {
+            top: '-',
+            left: '|',
+            top_left: '+',
+            top_right: '+',
+            bottom_left: '+',
+            bottom_right: '+',
+            left_tee: '+',
+            right_tee: '+',
+            top_tee: '+',
+            bottom_tee: '+',
+            cross: '+',
+        }
+    }
+
+    /// Gets given border character of the [`BorderSet`]
+    pub fn get(&self, border: u8) -> char {
+        match border {
+            Border::TOP | Border::BOTTOM => self.top,
+            Border::LEFT | Border::RIGHT => self.left,
+            _ if border == (Border::TOP | Border::LEFT) => self.top_left,
+            _ if border == (Border::TOP | Border::RIGHT) => self.top_right,
+            _ if border == (Border::BOTTOM | Border::LEFT) => self.bottom_left,
+            _ if border == (Border::BOTTOM | Border::RIGHT) => {
+                self.bottom_right
+            }
+            _ if border == borders!(LEFT, TOP, BOTTOM) => self.left_tee,
+            _ if border == borders!(RIGHT, TOP, BOTTOM) => self.right_tee,
+            _ if border == borders!(TOP, LEFT, RIGHT) => self.top_tee,
+            _ if border == borders!(BOTTOM, LEFT, RIGHT) => self.bottom_tee,
+            _ if border == borders!(TOP, BOTTOM, LEFT, RIGHT) => self.cross,
+            _ => ' ',
+        }
+    }
 }
 
 impl BorderType {
@@ -56,6 +132,45 @@ impl BorderType {
             BorderType::Thick => self.get_thick(border),
             BorderType::Double => self.get_double(border),
             BorderType::Dash => self.get_dash(border),
+            BorderType::Custom(set) => set.get(border),
+        }
+    }
+
+    /// Gets corner character joining horizontal side of the `hor` type with
+    /// vertical side of the `ver` type.
+    ///
+    /// Light, thicker and double lines are joined using the mixed corner
+    /// characters, other mismatched types use the corner of the horizontal
+    /// side type.
+    pub fn corner(hor: &BorderType, ver: &BorderType, corner: u8) -> char {
+        let id = match corner {
+            _ if corner == (Border::TOP | Border::LEFT) => 0,
+            _ if corner == (Border::TOP | Border::RIGHT) => 1,
+            _ if corner == (Border::BOTTOM | Border::LEFT) => 2,
+            _ if corner == (Border::BOTTOM | Border::RIGHT) => 3,
+            _ => return hor.get(corner),
+        };
+
+        let chars = match (hor.weight(), ver.weight()) {
+            (Some(1), Some(2)) => ['┎', '┒', '┖', '┚'],
+            (Some(2), Some(1)) => ['┍', '┑', '┕', '┙'],
+            (Some(1), Some(3)) => ['╓', '╖', '╙', '╜'],
+            (Some(3), Some(1)) => ['╒', '╕', '╘', '╛'],
+            _ => return hor.get(corner),
+        };
+        chars[id]
+    }
+
+    /// Gets weight of the line (1 for light, 2 for thicker and 3 for double),
+    /// `None` when it cannot be joined with other types
+    fn weight(&self) -> Option<u8> {
+        match self {
+            BorderType::Normal | BorderType::Rounded | BorderType::Dash => {
+                Some(1)
+            }
+            BorderType::Thicker => Some(2),
+            BorderType::Double => Some(3),
+            _ => None,
         }
     }
 
@@ -0,0 +1,434 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    buffer::Buffer,
+    geometry::{Rect, TextAlign, Unit, Vec2},
+    style::Style,
+};
+
+use super::{span::StrSpanExtension, widget::Widget, Element, Grid};
+
+/// Names of the months used in the [`Calendar`] title
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Weekday headers of the [`Calendar`], week starts with Monday
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Calendar widget rendering month with weekday headers, highlighted today
+/// and selected date
+///
+/// Selected date is stored in the shared [`CalendarState`], which can be
+/// changed using the [`Calendar`] functions (such as `select_next_day`) or
+/// directly. The displayed month is the month of the selected date.
+///
+/// ## Example usage:
+/// ```rust
+/// # use std::{cell::Cell, rc::Rc};
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::Rect,
+/// #     widgets::{Calendar, CalendarState, Date, Widget},
+/// # };
+/// let state = Rc::new(Cell::new(CalendarState::new(Date::new(2024, 2, 14))));
+/// let calendar = Calendar::new(state.clone())
+///     .today(Date::new(2024, 2, 1))
+///     .selected_style(Color::Yellow);
+///
+/// // Moves selection to the next week
+/// calendar.select_next_week();
+/// assert_eq!(state.get().selected, Date::new(2024, 2, 21));
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 20, 7));
+/// calendar.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct Calendar {
+    state: Rc<Cell<CalendarState>>,
+    today: Option<Date>,
+    style: Style,
+    title_style: Style,
+    header_style: Style,
+    today_style: Style,
+    sel_style: Style,
+}
+
+/// State of the [`Calendar`] widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarState {
+    pub selected: Date,
+}
+
+/// Minimal date representation used by the [`Calendar`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Calendar {
+    /// Creates new [`Calendar`] with given state
+    pub fn new(state: Rc<Cell<CalendarState>>) -> Self {
+        Self {
+            state,
+            today: None,
+            style: Default::default(),
+            title_style: Default::default(),
+            header_style: Default::default(),
+            today_style: Default::default(),
+            sel_style: Default::default(),
+        }
+    }
+
+    /// Sets the date highlighted as today
+    pub fn today<T>(mut self, today: T) -> Self
+    where
+        T: Into<Option<Date>>,
+    {
+        self.today = today.into();
+        self
+    }
+
+    /// Sets style of the [`Calendar`] days
+    pub fn style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets style of the [`Calendar`] title (month and year)
+    pub fn title_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.title_style = style.into();
+        self
+    }
+
+    /// Sets style of the weekday headers
+    pub fn header_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.header_style = style.into();
+        self
+    }
+
+    /// Sets style of the today's date
+    pub fn today_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.today_style = style.into();
+        self
+    }
+
+    /// Sets style of the selected date
+    pub fn selected_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.sel_style = style.into();
+        self
+    }
+
+    /// Selects the next day
+    pub fn select_next_day(&self) {
+        self.update(|s| s.next_day());
+    }
+
+    /// Selects the previous day
+    pub fn select_prev_day(&self) {
+        self.update(|s| s.prev_day());
+    }
+
+    /// Selects the same weekday in the next week
+    pub fn select_next_week(&self) {
+        self.update(|s| s.next_week());
+    }
+
+    /// Selects the same weekday in the previous week
+    pub fn select_prev_week(&self) {
+        self.update(|s| s.prev_week());
+    }
+
+    /// Selects the same day in the next month
+    pub fn next_month(&self) {
+        self.update(|s| s.next_month());
+    }
+
+    /// Selects the same day in the previous month
+    pub fn prev_month(&self) {
+        self.update(|s| s.prev_month());
+    }
+
+    /// Gets a copy of the [`CalendarState`]
+    pub fn get_state(&self) -> CalendarState {
+        self.state.get()
+    }
+}
+
+impl CalendarState {
+    /// Creates new [`CalendarState`] with given selected date
+    pub fn new(selected: Date) -> Self {
+        Self { selected }
+    }
+
+    /// Selects the next day
+    pub fn next_day(&mut self) {
+        self.selected = self.selected.add_days(1);
+    }
+
+    /// Selects the previous day
+    pub fn prev_day(&mut self) {
+        self.selected = self.selected.add_days(-1);
+    }
+
+    /// Selects the same weekday in the next week
+    pub fn next_week(&mut self) {
+        self.selected = self.selected.add_days(7);
+    }
+
+    /// Selects the same weekday in the previous week
+    pub fn prev_week(&mut self) {
+        self.selected = self.selected.add_days(-7);
+    }
+
+    /// Selects the same day in the next month (clamped to the month length)
+    pub fn next_month(&mut self) {
+        self.selected = self.selected.add_months(1);
+    }
+
+    /// Selects the same day in the previous month (clamped to the month
+    /// length)
+    pub fn prev_month(&mut self) {
+        self.selected = self.selected.add_months(-1);
+    }
+}
+
+impl Date {
+    /// Creates new [`Date`], month and day are clamped to the valid range
+    /// (e.g. month 13 is December and February 30 is February 28 or 29)
+    pub fn new(year: i32, month: u8, day: u8) -> Self {
+        let mut date = Self {
+            year,
+            month: month.clamp(1, 12),
+            day: 1,
+        };
+        date.day = day.clamp(1, date.days_in_month());
+        date
+    }
+
+    /// Gets year of the [`Date`]
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Gets month of the [`Date`], 1 is January and 12 is December
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Gets day of the month of the [`Date`], starting from 1
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Gets the current date (in UTC)
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self::from_days((secs / 86400) as i64)
+    }
+
+    /// Checks whether the year of the [`Date`] is leap year
+    pub fn is_leap_year(&self) -> bool {
+        (self.year % 4 == 0 && self.year % 100 != 0) || self.year % 400 == 0
+    }
+
+    /// Gets number of days in the month of the [`Date`]
+    pub fn days_in_month(&self) -> u8 {
+        match self.month {
+            2 if self.is_leap_year() => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Gets weekday of the [`Date`], 0 is Monday and 6 is Sunday
+    pub fn weekday(&self) -> u8 {
+        // 1970-01-01 was Thursday
+        (self.to_days() + 3).rem_euclid(7) as u8
+    }
+
+    /// Gets new [`Date`] moved by given number of days
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Gets new [`Date`] moved by given number of months. Day is clamped to
+    /// the length of the resulting month
+    pub fn add_months(&self, months: i32) -> Self {
+        let month = self.year * 12 + self.month as i32 - 1 + months;
+        let mut date =
+            Self::new(month.div_euclid(12), month.rem_euclid(12) as u8 + 1, 1);
+        date.day = self.day.min(date.days_in_month());
+        date
+    }
+
+    /// Gets number of days since 1970-01-01
+    fn to_days(self) -> i64 {
+        let month = self.month as i64;
+        let year = self.year as i64 - (month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let mp = (month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    /// Creates [`Date`] from number of days since 1970-01-01
+    fn from_days(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let doe = days - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = (mp + 2) % 12 + 1;
+        let year = yoe + era * 400 + (month <= 2) as i64;
+        Self::new(year as i32, month as u8, day as u8)
+    }
+}
+
+impl Widget for Calendar {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let selected = self.state.get().selected;
+        let title = format!(
+            "{} {}",
+            MONTHS[selected.month as usize - 1],
+            selected.year
+        );
+        let mut tbuffer = buffer.subset(Rect::new(
+            buffer.x(),
+            buffer.y(),
+            buffer.width(),
+            1,
+        ));
+        title
+            .style(self.title_style)
+            .align(TextAlign::Center)
+            .render(&mut tbuffer);
+        buffer.merge(tbuffer);
+
+        let rect = buffer.rect().inner((1, 0, 0, 0));
+        if rect.is_empty() {
+            return;
+        }
+
+        let mut gbuffer = buffer.subset(rect);
+        self.month_grid(&selected).render(&mut gbuffer);
+        buffer.merge(gbuffer);
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        let selected = self.state.get().selected;
+        2 + Self::weeks(&selected)
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        WEEKDAYS.len() * 3 - 1
+    }
+}
+
+impl Calendar {
+    /// Updates the state using given function
+    fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&mut CalendarState),
+    {
+        let mut state = self.state.get();
+        f(&mut state);
+        self.state.set(state);
+    }
+
+    /// Gets number of weeks the month of the given date spans over
+    fn weeks(date: &Date) -> usize {
+        let first = Date::new(date.year, date.month, 1);
+        (first.weekday() as usize + date.days_in_month() as usize).div_ceil(7)
+    }
+
+    /// Creates [`Grid`] containing weekday headers and days of the month
+    fn month_grid(&self, selected: &Date) -> Grid {
+        // Day columns are separated by one cell wide gap columns
+        let mut cols = vec![Unit::Length(2)];
+        for _ in 1..WEEKDAYS.len() {
+            cols.extend([Unit::Length(1), Unit::Length(2)]);
+        }
+        let mut grid =
+            Grid::new(cols, vec![Unit::Length(1); 1 + Self::weeks(selected)]);
+
+        for (col, day) in WEEKDAYS.iter().enumerate() {
+            grid.push(day.style(self.header_style), col * 2, 0);
+        }
+
+        let offset = Date::new(selected.year, selected.month, 1).weekday();
+        for day in 1..=selected.days_in_month() {
+            let date = Date::new(selected.year, selected.month, day);
+            let style = if date == *selected {
+                self.sel_style
+            } else if Some(date) == self.today {
+                self.today_style
+            } else {
+                self.style
+            };
+
+            let pos = (offset + day - 1) as usize;
+            let span = day.to_string().style(style).align(TextAlign::Right);
+            grid.push(span, pos % 7 * 2, pos / 7 + 1);
+        }
+        grid
+    }
+}
+
+// From implementations
+impl From<Calendar> for Box<dyn Widget> {
+    fn from(value: Calendar) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Calendar> for Element {
+    fn from(value: Calendar) -> Self {
+        Element::new(value)
+    }
+}
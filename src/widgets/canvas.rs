@@ -0,0 +1,143 @@
+use core::fmt;
+
+use crate::{
+    buffer::Buffer,
+    enums::Color,
+    geometry::{line_points, Rect, Vec2},
+};
+
+use super::{widget::Widget, Element};
+
+/// Canvas widget for drawing points, lines and rectangles
+///
+/// Drawing is done by the given function using [`Painter`], which is called
+/// on every render. Coordinates are relative to the canvas area and
+/// everything drawn outside of it is clipped.
+///
+/// ## Example usage:
+/// ```rust
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::{Rect, Vec2},
+/// #     widgets::{Canvas, Widget},
+/// # };
+/// let canvas = Canvas::new(|painter| {
+///     painter.rect(Rect::new(0, 0, 10, 5), Color::Blue);
+///     painter.line(Vec2::new(0, 0), Vec2::new(9, 4), Color::Red);
+///     painter.point(Vec2::new(5, 1), Color::Green);
+/// });
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 10, 5));
+/// canvas.render(&mut buffer);
+/// buffer.render();
+/// ```
+pub struct Canvas {
+    draw: Box<dyn Fn(&mut Painter)>,
+}
+
+impl Canvas {
+    /// Creates new [`Canvas`] drawn by the given function
+    pub fn new<F>(draw: F) -> Self
+    where
+        F: Fn(&mut Painter) + 'static,
+    {
+        Self {
+            draw: Box::new(draw),
+        }
+    }
+}
+
+impl Widget for Canvas {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+        (self.draw)(&mut Painter { buffer });
+    }
+
+    fn height(&self, size: &Vec2) -> usize {
+        size.y
+    }
+
+    fn width(&self, size: &Vec2) -> usize {
+        size.x
+    }
+}
+
+impl fmt::Debug for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Canvas").finish_non_exhaustive()
+    }
+}
+
+/// Draws into the [`Canvas`] area, coordinates are relative to the area and
+/// everything outside of it is clipped. Each drawn cell is filled with full
+/// block character with given color.
+#[derive(Debug)]
+pub struct Painter<'a> {
+    buffer: &'a mut Buffer,
+}
+
+impl Painter<'_> {
+    /// Gets size of the [`Canvas`] area
+    pub fn size(&self) -> Vec2 {
+        *self.buffer.size()
+    }
+
+    /// Draws point on given position
+    pub fn point(&mut self, pos: Vec2, color: Color) {
+        self.set(&pos, color);
+    }
+
+    /// Draws line between given positions using Bresenham's algorithm
+    pub fn line(&mut self, start: Vec2, end: Vec2, color: Color) {
+        let start = (start.x as f64, start.y as f64);
+        let end = (end.x as f64, end.y as f64);
+        for pos in line_points(start, end, &self.size()) {
+            self.set(&pos, color);
+        }
+    }
+
+    /// Draws outline of the given rectangle
+    pub fn rect(&mut self, rect: Rect, color: Color) {
+        if rect.width() == 0 || rect.height() == 0 {
+            return;
+        }
+
+        let (left, top) = (rect.x(), rect.y());
+        let right = left + rect.width() - 1;
+        let bottom = top + rect.height() - 1;
+        self.line(Vec2::new(left, top), Vec2::new(right, top), color);
+        self.line(Vec2::new(left, bottom), Vec2::new(right, bottom), color);
+        self.line(Vec2::new(left, top), Vec2::new(left, bottom), color);
+        self.line(Vec2::new(right, top), Vec2::new(right, bottom), color);
+    }
+}
+
+impl Painter<'_> {
+    /// Sets the cell on given relative position, clipped to the area
+    fn set(&mut self, pos: &Vec2, color: Color) {
+        let size = self.size();
+        if pos.x >= size.x || pos.y >= size.y {
+            return;
+        }
+
+        let pos = Vec2::new(self.buffer.x() + pos.x, self.buffer.y() + pos.y);
+        self.buffer.set_val('█', &pos);
+        self.buffer.set_fg(color, &pos);
+    }
+}
+
+// From implementations
+impl From<Canvas> for Box<dyn Widget> {
+    fn from(value: Canvas) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Canvas> for Element {
+    fn from(value: Canvas) -> Self {
+        Element::new(value)
+    }
+}
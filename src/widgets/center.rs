@@ -0,0 +1,122 @@
+use std::cmp::min;
+
+use crate::{
+    buffer::Buffer,
+    geometry::{Align, Rect, Vec2},
+};
+
+use super::{Element, Widget};
+
+/// Centers its child horizontally and vertically within the area, using
+/// the size of the child content. Child bigger than the area is clipped to
+/// it.
+///
+/// ## Example usage:
+/// ```rust
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     geometry::Rect,
+/// #     widgets::{Block, Center, Widget},
+/// # };
+/// // Puts the dialog to the middle of the area
+/// let dialog = Center::new(Block::vertical().title("Hi"));
+///
+/// // Centers only horizontally, child takes the whole height
+/// let column = Center::new(Block::vertical().title("Hi")).horizontal();
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 20, 9));
+/// dialog.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct Center<W = Element> {
+    child: W,
+    horizontal: bool,
+    vertical: bool,
+}
+
+impl<W> Center<W>
+where
+    W: Widget,
+{
+    /// Creates new [`Center`] centering given widget on both axes
+    pub fn new(child: W) -> Self {
+        Self {
+            child,
+            horizontal: true,
+            vertical: true,
+        }
+    }
+
+    /// Makes [`Center`] center its child only horizontally
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self.vertical = false;
+        self
+    }
+
+    /// Makes [`Center`] center its child only vertically
+    pub fn vertical(mut self) -> Self {
+        self.horizontal = false;
+        self.vertical = true;
+        self
+    }
+}
+
+impl<W> Widget for Center<W>
+where
+    W: Widget,
+{
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let size = *buffer.size();
+        let width = match self.horizontal {
+            true => min(self.child.width(&size), size.x),
+            false => size.x,
+        };
+        let height = match self.vertical {
+            true => min(self.child.height(&Vec2::new(width, size.y)), size.y),
+            false => size.y,
+        };
+
+        let x = buffer.x() + Align::Center.offset(width, size.x);
+        let y = buffer.y() + Align::Center.offset(height, size.y);
+        let mut cbuffer = buffer.subset(Rect::new(x, y, width, height));
+        self.child.render(&mut cbuffer);
+        buffer.merge(cbuffer);
+    }
+
+    fn height(&self, size: &Vec2) -> usize {
+        self.child.height(size)
+    }
+
+    fn width(&self, size: &Vec2) -> usize {
+        self.child.width(size)
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![&self.child]
+    }
+}
+
+// From implementations
+impl<W> From<Center<W>> for Box<dyn Widget>
+where
+    W: Widget + 'static,
+{
+    fn from(value: Center<W>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<W> From<Center<W>> for Element
+where
+    W: Widget + 'static,
+{
+    fn from(value: Center<W>) -> Self {
+        Element::new(value)
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{
+    buffer::Buffer,
+    geometry::{line_points, map_point, project, Rect, Vec2},
+    style::Style,
+};
+
+use super::{widget::Widget, Element};
+
+/// Chart widget plotting series of points using braille characters
+///
+/// Each cell contains 2x4 dots, which gives better resolution than plotting
+/// whole cells. Points are scaled to the chart area using the x and y bounds
+/// and points outside of the bounds are clipped. Points of each series can
+/// be connected with lines using [`Chart::lines`].
+///
+/// ## Example usage:
+/// ```rust
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::Rect,
+/// #     widgets::{Chart, Widget},
+/// # };
+/// let latency = vec![(0.0, 12.0), (1.0, 18.0), (2.0, 9.0), (3.0, 25.0)];
+/// let chart = Chart::new()
+///     .x_bounds(0.0, 3.0)
+///     .y_bounds(0.0, 30.0)
+///     .series("latency", latency, Color::Cyan)
+///     .lines(true);
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 20, 5));
+/// chart.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug, Default)]
+pub struct Chart {
+    series: Vec<Series>,
+    x_bounds: (f64, f64),
+    y_bounds: (f64, f64),
+    lines: bool,
+}
+
+/// Contains series name, its points and style
+#[derive(Debug)]
+struct Series {
+    pub name: String,
+    pub data: Vec<(f64, f64)>,
+    pub style: Style,
+}
+
+impl Chart {
+    /// Creates new empty [`Chart`] with both bounds set from 0 to 1
+    pub fn new() -> Self {
+        Self {
+            x_bounds: (0., 1.),
+            y_bounds: (0., 1.),
+            ..Default::default()
+        }
+    }
+
+    /// Sets minimum and maximum shown x value
+    pub fn x_bounds(mut self, min: f64, max: f64) -> Self {
+        self.x_bounds = (min, max);
+        self
+    }
+
+    /// Sets minimum and maximum shown y value
+    pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+        self.y_bounds = (min, max);
+        self
+    }
+
+    /// Adds series with given name, points and style to the [`Chart`]. When
+    /// series overlap in a cell, the later one sets the style
+    pub fn series<N, S>(
+        mut self,
+        name: N,
+        data: Vec<(f64, f64)>,
+        style: S,
+    ) -> Self
+    where
+        N: Into<String>,
+        S: Into<Style>,
+    {
+        self.series.push(Series {
+            name: name.into(),
+            data,
+            style: style.into(),
+        });
+        self
+    }
+
+    /// Sets whether consecutive points of each series are connected with
+    /// lines
+    pub fn lines(mut self, lines: bool) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    /// Gets names of the [`Chart`] series
+    pub fn series_names(&self) -> Vec<&str> {
+        self.series.iter().map(|s| s.name.as_str()).collect()
+    }
+}
+
+impl Widget for Chart {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let mut dots = Dots::new(*buffer.size());
+        let bounds = self.bounds();
+        for series in self.series.iter() {
+            if self.lines {
+                let points: Vec<_> = series
+                    .data
+                    .iter()
+                    .filter_map(|(x, y)| project(&dots.size, *x, *y, bounds))
+                    .collect();
+                for line in points.windows(2) {
+                    for pos in line_points(line[0], line[1], &dots.size) {
+                        dots.set(&pos, series.style);
+                    }
+                }
+            }
+
+            let area = Rect::from_coords(Vec2::new(0, 0), dots.size);
+            for (x, y) in series.data.iter() {
+                if let Some(pos) = map_point(&area, *x, *y, bounds) {
+                    dots.set(&pos, series.style);
+                }
+            }
+        }
+
+        for (i, (bits, style)) in dots.cells.into_iter().enumerate() {
+            let Some(style) = style else {
+                continue;
+            };
+            let pos = Vec2::new(
+                buffer.x() + i % buffer.width(),
+                buffer.y() + i / buffer.width(),
+            );
+            let val = char::from_u32(0x2800 + bits as u32).unwrap_or(' ');
+            buffer.set_val(val, &pos);
+            buffer.set_style(style, &pos);
+        }
+    }
+
+    fn height(&self, size: &Vec2) -> usize {
+        size.y
+    }
+
+    fn width(&self, size: &Vec2) -> usize {
+        size.x
+    }
+}
+
+impl Chart {
+    /// Gets bounds of the [`Chart`] as `(xmin, xmax, ymin, ymax)`
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        (
+            self.x_bounds.0,
+            self.x_bounds.1,
+            self.y_bounds.0,
+            self.y_bounds.1,
+        )
+    }
+}
+
+/// Braille dots of the chart, each cell contains 2x4 dots
+struct Dots {
+    size: Vec2,
+    width: usize,
+    cells: Vec<(u8, Option<Style>)>,
+}
+
+impl Dots {
+    /// Bits of the braille character dots, indexed by column and row
+    const BITS: [[u8; 4]; 2] =
+        [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+    /// Creates dots for area with given size in cells
+    fn new(size: Vec2) -> Self {
+        Self {
+            size: Vec2::new(size.x * 2, size.y * 4),
+            width: size.x,
+            cells: vec![(0, None); size.x * size.y],
+        }
+    }
+
+    /// Sets dot on given position, dots outside are clipped
+    fn set(&mut self, pos: &Vec2, style: Style) {
+        if pos.x >= self.size.x || pos.y >= self.size.y {
+            return;
+        }
+
+        let cell = &mut self.cells[pos.y / 4 * self.width + pos.x / 2];
+        cell.0 |= Self::BITS[pos.x % 2][pos.y % 4];
+        cell.1 = Some(style);
+    }
+}
+
+// From implementations
+impl From<Chart> for Box<dyn Widget> {
+    fn from(value: Chart) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Chart> for Element {
+    fn from(value: Chart) -> Self {
+        Element::new(value)
+    }
+}
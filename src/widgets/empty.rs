@@ -0,0 +1,60 @@
+use crate::{buffer::Buffer, geometry::Vec2};
+
+use super::{widget::Widget, Element};
+
+/// Empty widget, which renders nothing and has zero size
+///
+/// Unlike [`Spacer`](super::Spacer), which is meant to occupy the space given
+/// by its [`Constraint`](crate::geometry::Constraint), [`Empty`] is meant to
+/// be a placeholder for no widget, for example in conditional rendering. It
+/// can also be created from `()`.
+///
+/// ## Example usage:
+/// ```rust
+/// # use termint::widgets::{Element, Empty, StrSpanExtension};
+/// # let show = false;
+/// let widget: Element = match show {
+///     true => "Shown only sometimes".to_span().into(),
+///     false => ().into(),
+/// };
+/// assert!(widget.downcast_ref::<Empty>().is_some());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Empty;
+
+impl Empty {
+    /// Creates new [`Empty`] widget
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Widget for Empty {
+    fn render(&self, _buffer: &mut Buffer) {}
+
+    fn height(&self, _size: &Vec2) -> usize {
+        0
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        0
+    }
+}
+
+impl From<Empty> for Box<dyn Widget> {
+    fn from(value: Empty) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Empty> for Element {
+    fn from(value: Empty) -> Self {
+        Element::new(value)
+    }
+}
+
+impl From<()> for Element {
+    fn from(_: ()) -> Self {
+        Element::new(Empty)
+    }
+}
@@ -0,0 +1,173 @@
+use std::{cell::Cell, rc::Rc};
+
+use crate::{
+    buffer::Buffer,
+    enums::Modifier,
+    geometry::Vec2,
+    style::Style,
+    text::{char_width, str_width},
+};
+
+use super::{widget::Widget, Element};
+
+/// Gauge widget filling its area based on the ratio, with centered label
+///
+/// Ratio (from 0 to 1) is shared using `Rc<Cell<f64>>`, so it can be updated
+/// without recreating the widget. The label shows the percentage by default.
+/// Label characters on the filled part use the ratio style with reversed
+/// colors, the ones on the unfilled part use the track style.
+///
+/// ## Example usage:
+/// ```rust
+/// # use std::{cell::Cell, rc::Rc};
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::Rect,
+/// #     widgets::{Gauge, Widget},
+/// # };
+/// let ratio = Rc::new(Cell::new(0.0));
+/// let gauge = Gauge::new(ratio.clone()).ratio_style(Color::Green);
+///
+/// // Updates the progress
+/// ratio.set(0.69);
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 20, 1));
+/// gauge.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct Gauge {
+    ratio: Rc<Cell<f64>>,
+    label: Option<String>,
+    ratio_style: Style,
+    track_style: Style,
+}
+
+impl Gauge {
+    /// Creates new [`Gauge`] with given ratio
+    pub fn new(ratio: Rc<Cell<f64>>) -> Self {
+        Self {
+            ratio,
+            label: None,
+            ratio_style: Default::default(),
+            track_style: Default::default(),
+        }
+    }
+
+    /// Sets label of the [`Gauge`], replacing the percentage
+    pub fn label<T>(mut self, label: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets style of the filled part of the [`Gauge`]
+    pub fn ratio_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.ratio_style = style.into();
+        self
+    }
+
+    /// Sets style of the unfilled part of the [`Gauge`]
+    pub fn track_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.track_style = style.into();
+        self
+    }
+}
+
+impl Widget for Gauge {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let ratio = self.get_ratio();
+        let filled = buffer.x() + (buffer.width() as f64 * ratio) as usize;
+        for y in buffer.y()..buffer.y() + buffer.height() {
+            for x in buffer.x()..buffer.x() + buffer.width() {
+                let pos = Vec2::new(x, y);
+                if x < filled {
+                    buffer.set_val('█', &pos);
+                    buffer.set_style(self.ratio_style, &pos);
+                } else {
+                    buffer.set_val(' ', &pos);
+                    buffer.set_style(self.track_style, &pos);
+                }
+            }
+        }
+
+        let label = match &self.label {
+            Some(label) => label.clone(),
+            None => format!("{}%", (ratio * 100.).round()),
+        };
+        self.render_label(buffer, &label, filled);
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        1
+    }
+
+    fn width(&self, size: &Vec2) -> usize {
+        size.x
+    }
+}
+
+impl Gauge {
+    /// Gets the ratio clamped between 0 and 1
+    fn get_ratio(&self) -> f64 {
+        let ratio = self.ratio.get();
+        if ratio.is_nan() {
+            0.
+        } else {
+            ratio.clamp(0., 1.)
+        }
+    }
+
+    /// Renders label centered in the [`Gauge`], styling each character
+    /// based on which side of the fill edge it is
+    fn render_label(&self, buffer: &mut Buffer, label: &str, filled: usize) {
+        let width = str_width(label).min(buffer.width());
+        let mut pos = Vec2::new(
+            buffer.x() + (buffer.width() - width) / 2,
+            buffer.y() + (buffer.height() - 1) / 2,
+        );
+        let end = buffer.x() + buffer.width();
+
+        let fill_style = self.ratio_style.add_modifier(Modifier::INVERSED);
+        for c in label.chars() {
+            let cw = char_width(c);
+            if pos.x + cw > end {
+                break;
+            }
+
+            let style = if pos.x < filled {
+                fill_style
+            } else {
+                self.track_style
+            };
+            buffer.set_str_styled(c.to_string(), &pos, style);
+            pos.x += cw;
+        }
+    }
+}
+
+// From implementations
+impl From<Gauge> for Box<dyn Widget> {
+    fn from(value: Gauge) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Gauge> for Element {
+    fn from(value: Gauge) -> Self {
+        Element::new(value)
+    }
+}
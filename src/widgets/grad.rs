@@ -3,16 +3,18 @@ use std::cmp::min;
 
 use crate::{
     buffer::Buffer,
-    enums::{Color, Modifier, Wrap, RGB},
-    geometry::{Direction, TextAlign, Vec2},
+    enums::{Color, Interpolation, Modifier, Wrap, RGB},
+    geometry::{GradDirection, TextAlign, Vec2},
     style::Style,
-    text::Text,
+    text::{char_width, default_ellipsis, prefix, str_width, Text},
 };
 
 use super::{widget::Widget, Element};
 
 /// Text with gradient foreground
 ///
+/// Gradient can have multiple color stops, see [`Grad::stops`].
+///
 /// ## Example usage:
 /// ```rust
 /// # use termint::{
@@ -34,9 +36,9 @@ use super::{widget::Widget, Element};
 /// ```
 pub struct Grad {
     text: String,
-    fg_start: RGB,
-    fg_end: RGB,
-    direction: Direction,
+    stops: Vec<(f32, RGB)>,
+    direction: GradDirection,
+    interpolation: Interpolation,
     bg: Option<Color>,
     modifier: Modifier,
     align: TextAlign,
@@ -52,22 +54,62 @@ impl Grad {
         R: Into<RGB>,
         S: Into<RGB>,
     {
+        Self::stops(text, vec![(0.0, start.into()), (1.0, end.into())])
+    }
+
+    /// Creates new [`Grad`] with given text and gradient color stops.
+    ///
+    /// Each stop is a position from 0.0 to 1.0 (clamped) and its color. Stops
+    /// are sorted by their position and a single stop results in a solid
+    /// color.
+    ///
+    /// ## Example usage:
+    /// ```rust
+    /// # use termint::widgets::Grad;
+    /// let grad = Grad::stops(
+    ///     "Multi-stop gradient",
+    ///     vec![
+    ///         (0.0, (255, 0, 0).into()),
+    ///         (0.5, (0, 255, 0).into()),
+    ///         (1.0, (0, 0, 255).into()),
+    ///     ],
+    /// );
+    /// ```
+    pub fn stops<T>(text: T, mut stops: Vec<(f32, RGB)>) -> Self
+    where
+        T: Into<String>,
+    {
+        for (pos, _) in stops.iter_mut() {
+            *pos = pos.clamp(0.0, 1.0);
+        }
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
         Self {
             text: text.into(),
-            fg_start: start.into(),
-            fg_end: end.into(),
-            direction: Direction::Horizontal,
+            stops,
+            direction: GradDirection::Horizontal,
+            interpolation: Default::default(),
             bg: None,
             modifier: Modifier::empty(),
             align: Default::default(),
             wrap: Wrap::Word,
-            ellipsis: "...".to_string(),
+            ellipsis: default_ellipsis(),
         }
     }
 
-    /// Sets gradient direction of [`Grad`]
-    pub fn direction(mut self, direction: Direction) -> Self {
-        self.direction = direction;
+    /// Sets gradient direction of [`Grad`], accepts both
+    /// [`Direction`](crate::geometry::Direction) and [`GradDirection`]
+    pub fn direction<T>(mut self, direction: T) -> Self
+    where
+        T: Into<GradDirection>,
+    {
+        self.direction = direction.into();
+        self
+    }
+
+    /// Sets color space in which the gradient colors are interpolated
+    pub fn interpolate(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
         self
     }
 
@@ -81,20 +123,20 @@ impl Grad {
     }
 
     /// Sets [`Grad`] modifier to given modifiers
-    pub fn modifier(mut self, modifier: u8) -> Self {
+    pub fn modifier(mut self, modifier: u16) -> Self {
         self.modifier.clear();
         self.modifier.add(modifier);
         self
     }
 
     /// Adds given modifier to current [`Grad`] modifiers
-    pub fn add_modifier(mut self, flag: u8) -> Self {
+    pub fn add_modifier(mut self, flag: u16) -> Self {
         self.modifier.add(flag);
         self
     }
 
     /// Removes given modifier from the current [`Grad`] modifiers
-    pub fn remove_modifier(mut self, flag: u8) -> Self {
+    pub fn remove_modifier(mut self, flag: u16) -> Self {
         self.modifier.sub(flag);
         self
     }
@@ -163,14 +205,11 @@ impl Text for Grad {
     }
 
     fn get(&self) -> String {
-        let step = self.get_step(self.text.len() as i16 - 1);
-        let (mut r, mut g, mut b) =
-            (self.fg_start.r, self.fg_start.g, self.fg_start.b);
+        let len = self.text.chars().count().saturating_sub(1);
 
         let mut res = self.get_mods();
-        for c in self.text.chars() {
-            res += &format!("{}{c}", Color::Rgb(r, g, b).to_fg());
-            (r, g, b) = self.add_step((r, g, b), step);
+        for (i, c) in self.text.chars().enumerate() {
+            res += &format!("{}{c}", self.color_at(i, len).to_fg());
         }
         res += "\x1b[0m";
 
@@ -188,6 +227,11 @@ impl Text for Grad {
             self.bg.map_or_else(|| "".to_string(), |bg| bg.to_bg()),
         )
     }
+
+    fn ellipsis_end(&self, start: usize, len: usize, width: usize) -> usize {
+        let ellipsis = str_width(&self.ellipsis);
+        start + ellipsis + len.min(width.saturating_sub(ellipsis + start))
+    }
 }
 
 impl fmt::Display for Grad {
@@ -201,32 +245,24 @@ impl Grad {
     /// Renders [`Grad`] widget with word wrap
     fn render_word(&self, buffer: &mut Buffer, offset: usize) -> Vec2 {
         match self.direction {
-            Direction::Vertical => {
-                let height = min(
-                    self.height_word_wrap(buffer.size()) - 1,
-                    buffer.height(),
-                );
-                let step = self.get_step(height as i16);
-                self.render_words(
-                    &self.text,
-                    buffer,
-                    offset,
-                    (0, 0, 0),
-                    step,
-                    |t, b, p, r, s| self.render_ver_line(t, b, p, r, s),
-                )
+            GradDirection::Vertical => {
+                let height = self.grad_height(buffer);
+                self.render_words(&self.text, buffer, offset, |t, b, p, g| {
+                    self.render_ver_line(t, b, p, self.color_at(g.y, height))
+                })
             }
-            Direction::Horizontal => {
-                let width = min(buffer.width(), self.text.len());
-                let step = self.get_step(width as i16);
-                self.render_words(
-                    &self.text,
-                    buffer,
-                    offset,
-                    step,
-                    (0, 0, 0),
-                    |t, b, p, r, s| self.render_hor_line(t, b, p, r, s),
-                )
+            GradDirection::Horizontal => {
+                let width = min(buffer.width(), str_width(&self.text));
+                self.render_words(&self.text, buffer, offset, |t, b, p, g| {
+                    self.render_hor_line(t, b, p, g.x, width)
+                })
+            }
+            GradDirection::Diagonal => {
+                let len = min(buffer.width(), str_width(&self.text))
+                    + self.grad_height(buffer);
+                self.render_words(&self.text, buffer, offset, |t, b, p, g| {
+                    self.render_hor_line(t, b, p, g.x + g.y, len)
+                })
             }
         }
     }
@@ -234,83 +270,92 @@ impl Grad {
     /// Renders [`Grad`] widget with letter wrap
     fn render_letter(&self, buffer: &mut Buffer, offset: usize) -> Vec2 {
         match self.direction {
-            Direction::Vertical => {
-                let height = min(
-                    self.height_word_wrap(buffer.size()) - 1,
-                    buffer.height(),
-                );
-                let step = self.get_step(height as i16);
+            GradDirection::Vertical => {
+                let height = self.grad_height(buffer);
                 self.render_letters(
                     &self.text,
                     buffer,
                     offset,
-                    (0, 0, 0),
-                    step,
-                    |t, b, p, r, s| self.render_ver_line(t, b, p, r, s),
+                    |t, b, p, g| {
+                        self.render_ver_line(
+                            t,
+                            b,
+                            p,
+                            self.color_at(g.y, height),
+                        )
+                    },
                 )
             }
-            Direction::Horizontal => {
-                let width = min(buffer.width(), self.text.len());
-                let step = self.get_step(width as i16);
+            GradDirection::Horizontal => {
+                let width = min(buffer.width(), str_width(&self.text));
                 self.render_letters(
                     &self.text,
                     buffer,
                     offset,
-                    step,
-                    (0, 0, 0),
-                    |t, b, p, r, s| self.render_hor_line(t, b, p, r, s),
+                    |t, b, p, g| self.render_hor_line(t, b, p, g.x, width),
+                )
+            }
+            GradDirection::Diagonal => {
+                let len = min(buffer.width(), str_width(&self.text))
+                    + self.grad_height(buffer);
+                self.render_letters(
+                    &self.text,
+                    buffer,
+                    offset,
+                    |t, b, p, g| self.render_hor_line(t, b, p, g.x + g.y, len),
                 )
             }
         }
     }
 
+    /// Gets number of lines the vertical gradient goes through
+    fn grad_height(&self, buffer: &Buffer) -> usize {
+        min(self.height_word_wrap(buffer.size()) - 1, buffer.height())
+    }
+
     /// Renders given text with word wrap
     fn render_words<F>(
         &self,
         text: &str,
         buffer: &mut Buffer,
         mut offset: usize,
-        step_x: (i16, i16, i16),
-        step_y: (i16, i16, i16),
         render_line: F,
     ) -> Vec2
     where
-        F: Fn(String, &mut Buffer, &Vec2, (u8, u8, u8), (i16, i16, i16)),
+        F: Fn(String, &mut Buffer, &Vec2, Vec2),
     {
         let mut line = Vec::<&str>::new();
         let mut coords = Vec2::new(offset, buffer.y());
 
-        let mut rgb = (self.fg_start.r, self.fg_start.g, self.fg_start.b);
-        if self.text.len() + offset >= buffer.width() {
-            for _ in 0..offset {
-                rgb = self.add_step(rgb, step_x);
-            }
+        // Position in the gradient (column and line)
+        let mut grad = Vec2::new(0, 0);
+        if str_width(&self.text) + offset >= buffer.width() {
+            grad.x = offset;
         }
 
         for word in text.split_whitespace() {
-            if coords.x + word.len() + !line.is_empty() as usize
+            if coords.x + str_width(word) + !line.is_empty() as usize
                 > buffer.width()
             {
                 if coords.y + 1 >= buffer.y() + buffer.height()
-                    || word.len() > buffer.width()
+                    || str_width(word) > buffer.width()
                 {
                     let mut line_str = line.join(" ");
-                    let sum = coords.x + self.ellipsis.len();
+                    let sum = coords.x + str_width(&self.ellipsis);
                     if sum + offset >= buffer.width() {
-                        let end = buffer
-                            .width()
-                            .saturating_sub(self.ellipsis.len() + offset);
-                        line_str = line_str[..end].to_string();
+                        let end = buffer.width().saturating_sub(
+                            str_width(&self.ellipsis) + offset,
+                        );
+                        line_str = line_str.chars().take(end).collect();
                     }
 
                     line_str.push_str(&self.ellipsis);
-                    coords.x = line.len();
+                    coords.x = str_width(&line_str) + offset;
                     render_line(
                         line_str,
                         buffer,
                         &Vec2::new(buffer.x() + offset, coords.y),
-                        rgb,
-                        step_x,
+                        grad,
                     );
                     return coords;
                 }
@@ -319,15 +364,14 @@ impl Grad {
                     line.join(" "),
                     buffer,
                     &Vec2::new(buffer.x() + offset, coords.y),
-                    rgb,
-                    step_x,
+                    grad,
                 );
                 offset = 0;
                 (coords.x, coords.y) = (0, coords.y + 1);
-                rgb = self.add_step(rgb, step_y);
+                grad.y += 1;
                 line.clear();
             }
-            coords.x += word.len() + !line.is_empty() as usize;
+            coords.x += str_width(word) + !line.is_empty() as usize;
             line.push(word);
         }
 
@@ -336,8 +380,7 @@ impl Grad {
                 line.join(" "),
                 buffer,
                 &Vec2::new(buffer.x() + offset, coords.y),
-                rgb,
-                step_x,
+                grad,
             );
         }
 
@@ -350,44 +393,40 @@ impl Grad {
         text: &str,
         buffer: &mut Buffer,
         offset: usize,
-        step_x: (i16, i16, i16),
-        step_y: (i16, i16, i16),
         render_line: F,
     ) -> Vec2
     where
-        F: Fn(String, &mut Buffer, &Vec2, (u8, u8, u8), (i16, i16, i16)),
+        F: Fn(String, &mut Buffer, &Vec2, Vec2),
     {
-        let mut chars = text.chars().peekable();
+        let mut rest = text;
         let mut coords = Vec2::new(offset, buffer.y());
-        let mut rgb = (self.fg_start.r, self.fg_start.g, self.fg_start.b);
-        for _ in 0..offset {
-            rgb = self.add_step(rgb, step_x);
-        }
+        let mut grad = Vec2::new(offset, 0);
 
         let mut line = String::new();
         for _ in 0..buffer.height() {
-            if chars.peek().is_none() {
+            if rest.is_empty() {
                 coords.y -= 1;
                 return coords;
             }
 
-            line = chars.by_ref().take(buffer.width()).collect();
-            coords.x = line.len();
+            line = prefix(rest, buffer.width()).to_string();
+            rest = &rest[line.len()..];
+            coords.x = str_width(&line);
             let pos = Vec2::new(buffer.x(), coords.y);
-            render_line(line.clone(), buffer, &pos, rgb, step_x);
+            render_line(line.clone(), buffer, &pos, grad);
 
             coords.y += 1;
-            rgb = self.add_step(rgb, step_y);
+            grad.y += 1;
         }
 
         coords.y -= 1;
-        if self.text.len() > buffer.area() {
-            let end = buffer.width().saturating_sub(self.ellipsis.len());
-            line = line[..end].to_string();
+        if !rest.is_empty() {
+            let end = buffer.width().saturating_sub(str_width(&self.ellipsis));
+            line = prefix(&line, end).to_string();
             line.push_str(&self.ellipsis);
 
             let pos = Vec2::new(buffer.x(), coords.y);
-            render_line(line, buffer, &pos, rgb, step_x);
+            render_line(line, buffer, &pos, grad);
         }
         coords
     }
@@ -398,27 +437,26 @@ impl Grad {
         line: String,
         buffer: &mut Buffer,
         pos: &Vec2,
-        (mut r, mut g, mut b): (u8, u8, u8),
-        step: (i16, i16, i16),
+        start: usize,
+        len: usize,
     ) {
-        let offset = self.get_align_offset(buffer, line.len());
-        for _ in 0..offset {
-            (r, g, b) = self.add_step((r, g, b), step);
-        }
-
-        let mut style = Style::new()
-            .fg(Color::Rgb(r, g, b))
-            .bg(self.bg)
-            .modifier(self.modifier.val());
+        let offset = self.get_align_offset(buffer, str_width(&line));
+        let mut id = start + offset;
 
+        let mut style = Style::new().bg(self.bg).modifier(self.modifier.val());
         let mut coords = Vec2::new(pos.x + offset, pos.y);
         for c in line.chars() {
+            let width = char_width(c);
+            if width == 0 {
+                continue;
+            }
+
+            style = style.fg(self.color_at(id, len));
             buffer.set_val(c, &coords);
             buffer.set_style(style, &coords);
 
-            coords.x += 1;
-            (r, g, b) = self.add_step((r, g, b), step);
-            style = style.fg(Color::Rgb(r, g, b));
+            coords.x += width;
+            id += 1;
         }
     }
 
@@ -428,43 +466,49 @@ impl Grad {
         line: String,
         buffer: &mut Buffer,
         pos: &Vec2,
-        (r, g, b): (u8, u8, u8),
-        _step: (i16, i16, i16),
+        fg: Color,
     ) {
-        let offset = self.get_align_offset(buffer, line.len());
-        let style = Style::new().fg(Color::Rgb(r, g, b)).bg(self.bg);
+        let offset = self.get_align_offset(buffer, str_width(&line));
+        let style = Style::new().fg(fg).bg(self.bg);
         buffer.set_str_styled(line, &Vec2::new(pos.x + offset, pos.y), style);
     }
 
     /// Gets text alignment offset
     fn get_align_offset(&self, buffer: &Buffer, len: usize) -> usize {
         match self.align {
-            TextAlign::Left => 0,
+            TextAlign::Left | TextAlign::Justify => 0,
             TextAlign::Center => buffer.width().saturating_sub(len) >> 1,
             TextAlign::Right => buffer.width().saturating_sub(len),
         }
     }
 
-    /// Gets step per character based on start and end foreground color
-    fn get_step(&self, len: i16) -> (i16, i16, i16) {
-        (
-            (self.fg_end.r as i16 - self.fg_start.r as i16) / len,
-            (self.fg_end.g as i16 - self.fg_start.g as i16) / len,
-            (self.fg_end.b as i16 - self.fg_start.b as i16) / len,
-        )
-    }
+    /// Gets gradient color on given position out of `len` positions
+    fn color_at(&self, pos: usize, len: usize) -> Color {
+        let t = match len {
+            0 => 0.0,
+            _ => (pos as f32 / len as f32).min(1.0),
+        };
 
-    /// Adds given step to RGB value in tuple
-    fn add_step(
-        &self,
-        rgb: (u8, u8, u8),
-        step: (i16, i16, i16),
-    ) -> (u8, u8, u8) {
-        (
-            (rgb.0 as i16 + step.0) as u8,
-            (rgb.1 as i16 + step.1) as u8,
-            (rgb.2 as i16 + step.2) as u8,
-        )
+        let Some(&(mut prev, mut prev_rgb)) = self.stops.first() else {
+            return Color::Default;
+        };
+        let mut rgb = prev_rgb;
+        for &(pos, stop) in self.stops.iter() {
+            if t <= pos {
+                rgb = if pos <= prev {
+                    stop
+                } else {
+                    let alpha = ((t - prev) / (pos - prev)) as f64;
+                    match self.interpolation {
+                        Interpolation::Rgb => prev_rgb.blend(stop, alpha),
+                        Interpolation::Hsl => prev_rgb.blend_hsl(stop, alpha),
+                    }
+                };
+                break;
+            }
+            (prev, prev_rgb, rgb) = (pos, stop, stop);
+        }
+        Color::Rgb(rgb.r, rgb.g, rgb.b)
     }
 
     /// Gets height of the [`Grad`] when using word wrap
@@ -473,7 +517,7 @@ impl Grad {
 
         let words: Vec<&str> = self.text.split_whitespace().collect();
         for word in words {
-            let len = word.len();
+            let len = str_width(word);
             if coords.x + len + 1 > size.x {
                 coords.y += 1;
                 coords.x = 0;
@@ -499,7 +543,7 @@ impl Grad {
 
     /// Gets size of the [`Grad`] when using letter wrap
     fn size_letter_wrap(&self, size: usize) -> usize {
-        (self.text.len() as f32 / size as f32).ceil() as usize
+        (str_width(&self.text) as f32 / size as f32).ceil() as usize
     }
 }
 
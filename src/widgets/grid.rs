@@ -30,14 +30,20 @@ pub struct Grid {
     children: Vec<GridChild>,
     rows: Vec<Unit>,
     cols: Vec<Unit>,
+    col_gap: usize,
+    row_gap: usize,
+    next: usize,
 }
 
-/// Contains grid child and row and column in which it's located
+/// Contains grid child, row and column in which it's located and number of
+/// rows and columns it spans
 #[derive(Debug)]
 struct GridChild {
     pub child: Box<dyn Widget>,
     pub row: usize,
     pub col: usize,
+    pub row_span: usize,
+    pub col_span: usize,
 }
 
 impl Grid {
@@ -53,6 +59,9 @@ impl Grid {
             children: vec![],
             rows: rows.into_iter().map(|r| r.into()).collect(),
             cols: cols.into_iter().map(|c| c.into()).collect(),
+            col_gap: 0,
+            row_gap: 0,
+            next: 0,
         }
     }
 
@@ -61,6 +70,18 @@ impl Grid {
         Self::default()
     }
 
+    /// Sets size of the gap between the columns
+    pub fn col_gap(mut self, gap: usize) -> Self {
+        self.col_gap = gap;
+        self
+    }
+
+    /// Sets size of the gap between the rows
+    pub fn row_gap(mut self, gap: usize) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
     /// Adds given row to current rows
     pub fn row(&mut self, row: Unit) {
         self.rows.push(row);
@@ -80,24 +101,63 @@ impl Grid {
     where
         T: Into<Box<dyn Widget>>,
     {
-        self.children.push(GridChild {
-            child: child.into(),
-            row,
-            col,
-        })
+        self.push(child, col, row)
     }
 
     /// Adds child to the grid to given row and column
     pub fn push<T>(&mut self, child: T, col: usize, row: usize)
     where
         T: Into<Box<dyn Widget>>,
+    {
+        self.push_span(child, col, row, 1, 1)
+    }
+
+    /// Adds child to the grid to given row and column and returns the grid,
+    /// which allows building the [`Grid`] in a single expression
+    pub fn with<T>(mut self, child: T, col: usize, row: usize) -> Self
+    where
+        T: Into<Box<dyn Widget>>,
+    {
+        self.push(child, col, row);
+        self
+    }
+
+    /// Adds child to the grid spanning given number of columns and rows,
+    /// starting at given column and row. Spans going out of the grid are
+    /// clamped to the grid bounds
+    pub fn push_span<T>(
+        &mut self,
+        child: T,
+        col: usize,
+        row: usize,
+        col_span: usize,
+        row_span: usize,
+    ) where
+        T: Into<Box<dyn Widget>>,
     {
         self.children.push(GridChild {
             child: child.into(),
             row,
             col,
+            row_span: row_span.max(1),
+            col_span: col_span.max(1),
         })
     }
+
+    /// Adds child to the next free cell of the grid. Cells are filled from
+    /// left to right, top to bottom, wrapping at the number of columns
+    pub fn push_auto<T>(&mut self, child: T)
+    where
+        T: Into<Box<dyn Widget>>,
+    {
+        let cols = self.cols.len().max(1);
+        while self.occupied(self.next % cols, self.next / cols) {
+            self.next += 1;
+        }
+
+        self.push(child, self.next % cols, self.next / cols);
+        self.next += 1;
+    }
 }
 
 impl Widget for Grid {
@@ -106,56 +166,110 @@ impl Widget for Grid {
             return;
         }
 
-        let (cols, rows) = self.get_sizes(buffer);
+        let (cols, rows) = self.get_sizes(buffer.size());
+
+        for child in self.children.iter() {
+            let (Some(x), Some(y)) = (
+                Self::span(&cols, child.col, child.col_span),
+                Self::span(&rows, child.row, child.row_span),
+            ) else {
+                continue;
+            };
 
-        for GridChild { child, row, col } in self.children.iter() {
-            let mut cbuffer = buffer.subset(Rect::new(
-                buffer.x() + cols[*col].y,
-                buffer.y() + rows[*row].y,
-                cols[*col].x,
-                rows[*row].x,
+            // Tracks overflowing the grid are clipped to its area
+            let rect = buffer.rect().intersection(&Rect::new(
+                buffer.x() + x.y,
+                buffer.y() + y.y,
+                x.x,
+                y.x,
             ));
-            child.render(&mut cbuffer);
+            if rect.is_empty() {
+                continue;
+            }
+
+            let mut cbuffer = buffer.subset(rect);
+            child.child.render(&mut cbuffer);
             buffer.merge(cbuffer);
         }
     }
 
     fn height(&self, size: &Vec2) -> usize {
-        let mut height = 0;
-        for row in self.rows.iter() {
-            match row {
-                Unit::Length(len) => height += len,
-                Unit::Percent(p) => height += size.y * p / 100,
-                _ => {}
-            }
-        }
-        height
+        let (_, rows) = self.get_sizes(size);
+        Self::measure(&self.rows, &rows, self.row_gap)
     }
 
     fn width(&self, size: &Vec2) -> usize {
-        let mut width = 0;
-        for col in self.cols.iter() {
-            match col {
-                Unit::Length(len) => width += len,
-                Unit::Percent(p) => width += size.y * p / 100,
-                _ => {}
-            }
-        }
-        width
+        let (cols, _) = self.get_sizes(size);
+        Self::measure(&self.cols, &cols, self.col_gap)
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        self.children.iter().map(|c| c.child.as_ref()).collect()
     }
 }
 
 impl Grid {
-    /// Gets sizes and starting positions of each row and column
-    fn get_sizes(&self, buffer: &mut Buffer) -> (Vec<Vec2>, Vec<Vec2>) {
-        (
-            Self::get_size(&self.cols, buffer.width()),
-            Self::get_size(&self.rows, buffer.height()),
-        )
+    /// Checks whether the cell on given column and row is covered by any
+    /// child
+    fn occupied(&self, col: usize, row: usize) -> bool {
+        self.children.iter().any(|c| {
+            (c.col..c.col + c.col_span).contains(&col)
+                && (c.row..c.row + c.row_span).contains(&row)
+        })
+    }
+
+    /// Gets size and starting position of given number of tracks starting
+    /// at given track, clamped to the number of the tracks
+    fn span(tracks: &[Vec2], start: usize, span: usize) -> Option<Vec2> {
+        let first = tracks.get(start)?;
+        let last = tracks[(start + span).min(tracks.len()) - 1];
+        Some(Vec2::new(last.y + last.x - first.y, first.y))
+    }
+
+    /// Gets sizes and starting positions of each row and column. The axis
+    /// that uses [`Unit::Aspect`] is resolved last, since it depends on the
+    /// other one
+    fn get_sizes(&self, size: &Vec2) -> (Vec<Vec2>, Vec<Vec2>) {
+        let aspect = |u: &Unit| matches!(u, Unit::Aspect(_));
+        let (width, height) = (size.x, size.y);
+        if self.cols.iter().any(aspect) {
+            let rows = Self::get_size(&self.rows, height, self.row_gap, &[]);
+            let cols = Self::get_size(&self.cols, width, self.col_gap, &rows);
+            (cols, rows)
+        } else {
+            let cols = Self::get_size(&self.cols, width, self.col_gap, &[]);
+            let rows = Self::get_size(&self.rows, height, self.row_gap, &cols);
+            (cols, rows)
+        }
+    }
+
+    /// Gets size taken by the given tracks including the gaps between them.
+    /// [`Unit::Fill`] tracks aren't counted, since they only take the space
+    /// left by the other tracks
+    fn measure(units: &[Unit], tracks: &[Vec2], gap: usize) -> usize {
+        let len: usize = units
+            .iter()
+            .zip(tracks)
+            .filter(|(unit, _)| !matches!(unit, Unit::Fill(_)))
+            .map(|(_, track)| track.x)
+            .sum();
+        len + Self::gaps(units, gap)
+    }
+
+    /// Gets total size of the gaps between given units
+    fn gaps(units: &[Unit], gap: usize) -> usize {
+        units.len().saturating_sub(1) * gap
     }
 
-    /// Gets sizes and positions of given units
-    fn get_size(units: &[Unit], size: usize) -> Vec<Vec2> {
+    /// Gets sizes and positions of given units separated by given gap,
+    /// `other` contains sizes of the perpendicular tracks, which are used by
+    /// [`Unit::Aspect`]
+    fn get_size(
+        units: &[Unit],
+        size: usize,
+        gap: usize,
+        other: &[Vec2],
+    ) -> Vec<Vec2> {
         let mut total = 0;
         let mut fills_total = 0;
 
@@ -165,14 +279,20 @@ impl Grid {
             let len = match unit {
                 Unit::Length(len) => *len,
                 Unit::Percent(p) => size * p / 100,
+                Unit::Aspect(ratio) => other
+                    .get(sizes.len())
+                    .or(other.last())
+                    .map_or(0, |o| (o.x as f64 * ratio).round() as usize),
                 Unit::Fill(f) => {
                     fills_total += f;
                     fills.push(sizes.len());
-                    *f
+                    sizes.push(Vec2::new(*f, total));
+                    total += gap;
+                    continue;
                 }
             };
             sizes.push(Vec2::new(len, total));
-            total += len;
+            total += len + gap;
         }
 
         if fills_total == 0 {
@@ -180,17 +300,17 @@ impl Grid {
         }
 
         let mut pos = 0;
-        let remain = size.saturating_sub(total);
+        let remain = size.saturating_sub(total - gap);
         for (i, row) in units.iter().enumerate() {
             match row {
                 Unit::Fill(f) => {
                     sizes[i].x = remain * f / fills_total;
                     sizes[i].y = pos;
-                    pos += sizes[i].x;
+                    pos += sizes[i].x + gap;
                 }
                 _ => {
                     sizes[i].y = pos;
-                    pos += sizes[i].x;
+                    pos += sizes[i].x + gap;
                 }
             }
         }
@@ -1,9 +1,12 @@
-use std::cmp::{max, min};
+use std::{
+    cmp::{max, min},
+    fmt,
+};
 
 use crate::{
     buffer::Buffer,
     enums::Color,
-    geometry::{Constraint, Direction, Padding, Rect, Vec2},
+    geometry::{Align, Constraint, Direction, Justify, Padding, Rect, Vec2},
     style::Style,
 };
 
@@ -31,19 +34,21 @@ use super::{widget::Widget, Element};
 /// layout.render(&mut buffer);
 /// buffer.render();
 /// ```
-#[derive(Debug)]
 pub struct Layout {
     direction: Direction,
     children: Vec<LayoutChild>,
     style: Style,
     padding: Padding,
-    center: bool,
+    justify: Justify,
+    cross_align: Option<Align>,
+    wrap: bool,
+    on_overflow: Option<Box<dyn Fn(usize, usize)>>,
 }
 
 /// Contains layout child and constraint of its size
 #[derive(Debug)]
 struct LayoutChild {
-    pub child: Box<dyn Widget>,
+    pub child: Element,
     pub constraint: Constraint,
 }
 
@@ -112,8 +117,51 @@ impl Layout {
     }
 
     /// Makes [`Layout`] center its content in its direction
-    pub fn center(mut self) -> Self {
-        self.center = true;
+    pub fn center(self) -> Self {
+        self.justify(Justify::Center)
+    }
+
+    /// Sets how the free space is distributed between the children in the
+    /// [`Layout`] direction. Only used when no child has `Fill`
+    /// [`Constraint`], which takes all the free space
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Sets alignment of the children on the cross axis (horizontal in
+    /// vertical [`Layout`]). Children take the size of their content on the
+    /// cross axis, except for children with `Fill` [`Constraint`], which
+    /// still stretch. When not set, all children stretch
+    pub fn cross_align(mut self, align: Align) -> Self {
+        self.cross_align = Some(align);
+        self
+    }
+
+    /// Makes [`Layout`] wrap its children. Children that don't fit into the
+    /// row (or column in vertical [`Layout`]) are moved to the next one.
+    /// Children keep the sizes given by their [`Constraint`] (`Fill` takes
+    /// the size of its content) and child bigger than the whole row gets its
+    /// own row, where it's clipped.
+    ///
+    /// Each row is as tall as its tallest child (or as wide as its widest
+    /// child in vertical [`Layout`]), so the height of the horizontal
+    /// [`Layout`] is sum of the row heights, and the width of the vertical
+    /// [`Layout`] is sum of the column widths.
+    pub fn wrap(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Sets function that is called when children of the [`Layout`] don't
+    /// fit into it. The function gets the size needed by the children and
+    /// the available size. Only checked in debug builds, which helps to
+    /// catch layout bugs early without slowing down the release build.
+    pub fn on_overflow<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, usize) + 'static,
+    {
+        self.on_overflow = Some(Box::new(callback));
         self
     }
 
@@ -128,7 +176,7 @@ impl Layout {
         C: Into<Constraint>,
     {
         self.children.push(LayoutChild {
-            child: child.into(),
+            child: Element::from(child.into()),
             constraint: constraint.into(),
         });
     }
@@ -140,10 +188,79 @@ impl Layout {
         C: Into<Constraint>,
     {
         self.children.push(LayoutChild {
-            child: child.into(),
+            child: Element::from(child.into()),
             constraint: constraint.into(),
         });
     }
+
+    /// Adds child with its [`Constraint`] to the [`Layout`] and returns it,
+    /// which allows building the [`Layout`] in a single expression
+    pub fn child<T, C>(mut self, child: T, constraint: C) -> Self
+    where
+        T: Into<Box<dyn Widget>>,
+        C: Into<Constraint>,
+    {
+        self.push(child, constraint);
+        self
+    }
+
+    /// Pushes given children to the [`Layout`], each with `Fill(1)`
+    /// [`Constraint`], which splits the space into equal parts. Parts differ
+    /// by at most one cell, the remainder goes to the first children.
+    pub fn push_equal<I, T>(&mut self, children: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Box<dyn Widget>>,
+    {
+        for child in children {
+            self.push(child, Constraint::Fill(1));
+        }
+    }
+
+    /// Inserts child with its [`Constraint`] to the [`Layout`] at given
+    /// index, shifting all the children after it
+    ///
+    /// # Panics
+    /// Panics if the index is greater than the number of children
+    pub fn insert<T, C>(&mut self, idx: usize, child: T, constraint: C)
+    where
+        T: Into<Box<dyn Widget>>,
+        C: Into<Constraint>,
+    {
+        self.children.insert(
+            idx,
+            LayoutChild {
+                child: Element::from(child.into()),
+                constraint: constraint.into(),
+            },
+        );
+    }
+
+    /// Removes child with given index from the [`Layout`] together with its
+    /// [`Constraint`]. Returns the removed child or `None` when the index is
+    /// out of bounds
+    pub fn remove(&mut self, idx: usize) -> Option<Element> {
+        if idx >= self.children.len() {
+            return None;
+        }
+        Some(self.children.remove(idx).child)
+    }
+
+    /// Gets reference to the child with given index, `None` when the index
+    /// is out of bounds
+    pub fn get(&self, idx: usize) -> Option<&Element> {
+        self.children.get(idx).map(|c| &c.child)
+    }
+
+    /// Gets number of children in the [`Layout`]
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Checks whether the [`Layout`] has no children
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
 }
 
 impl Widget for Layout {
@@ -160,6 +277,11 @@ impl Widget for Layout {
         }
 
         let mut cbuffer = buffer.subset(rect);
+        if self.wrap {
+            self.wrap_render(&mut cbuffer, rect);
+            buffer.merge(cbuffer);
+            return;
+        }
         match self.direction {
             Direction::Vertical => self.ver_render(&mut cbuffer, rect),
             Direction::Horizontal => self.hor_render(&mut cbuffer, rect),
@@ -176,6 +298,7 @@ impl Widget for Layout {
             size.y.saturating_sub(self.padding.get_vertical()),
         );
         let height = match self.direction {
+            _ if self.wrap => self.wrap_size(&size).y,
             Direction::Vertical => {
                 self.size_sd(&size, size.y, |c, s| c.height(s))
             }
@@ -193,6 +316,7 @@ impl Widget for Layout {
             size.y.saturating_sub(self.padding.get_vertical()),
         );
         let width = match self.direction {
+            _ if self.wrap => self.wrap_size(&size).x,
             Direction::Vertical => self.ver_width(&size),
             Direction::Horizontal => {
                 self.size_sd(&size, size.x, |c, s| c.width(s))
@@ -200,6 +324,24 @@ impl Widget for Layout {
         };
         width + self.padding.get_horizontal()
     }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        self.children.iter().map(|c| c.child.as_widget()).collect()
+    }
+}
+
+impl fmt::Debug for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Layout")
+            .field("direction", &self.direction)
+            .field("children", &self.children)
+            .field("style", &self.style)
+            .field("padding", &self.padding)
+            .field("justify", &self.justify)
+            .field("cross_align", &self.cross_align)
+            .field("wrap", &self.wrap)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for Layout {
@@ -209,7 +351,10 @@ impl Default for Layout {
             children: Vec::new(),
             style: Style::new(),
             padding: Default::default(),
-            center: false,
+            justify: Default::default(),
+            cross_align: None,
+            wrap: false,
+            on_overflow: None,
         }
     }
 }
@@ -217,14 +362,23 @@ impl Default for Layout {
 impl Layout {
     /// Renders layout
     fn ver_render(&self, buffer: &mut Buffer, rect: Rect) {
-        let (sizes, mut rect) = self.ver_sizes(rect);
+        let (sizes, gaps) = self.ver_sizes(rect);
+        let mut rect = rect;
         for (i, s) in sizes.iter().enumerate() {
+            rect = rect.inner(Padding::top(gaps[i]));
             let csize = min(*s, rect.height());
-            let mut cbuffer = buffer.subset(Rect::from_coords(
-                *rect.pos(),
-                Vec2::new(rect.width(), csize),
-            ));
-            self.children[i].child.render(&mut cbuffer);
+            let LayoutChild { child, constraint } = &self.children[i];
+            let (x, width) = match self.cross_align {
+                Some(align) if !matches!(constraint, Constraint::Fill(_)) => {
+                    let size = Vec2::new(rect.width(), csize);
+                    let width = min(child.width(&size), rect.width());
+                    (rect.x() + align.offset(width, rect.width()), width)
+                }
+                _ => (rect.x(), rect.width()),
+            };
+            let mut cbuffer =
+                buffer.subset(Rect::new(x, rect.y(), width, csize));
+            child.render(&mut cbuffer);
             buffer.merge(cbuffer);
             rect = rect.inner(Padding::top(csize));
         }
@@ -232,72 +386,186 @@ impl Layout {
 
     /// Renders layout
     fn hor_render(&self, buffer: &mut Buffer, rect: Rect) {
-        let (sizes, mut rect) = self.hor_sizes(rect);
+        let (sizes, gaps) = self.hor_sizes(rect);
+        let mut rect = rect;
         for (i, s) in sizes.iter().enumerate() {
+            rect = rect.inner(Padding::left(gaps[i]));
             let csize = min(*s, rect.width());
-            let mut cbuffer = buffer.subset(Rect::from_coords(
-                *rect.pos(),
-                Vec2::new(csize, rect.height()),
-            ));
-            self.children[i].child.render(&mut cbuffer);
+            let LayoutChild { child, constraint } = &self.children[i];
+            let (y, height) = match self.cross_align {
+                Some(align) if !matches!(constraint, Constraint::Fill(_)) => {
+                    let size = Vec2::new(csize, rect.height());
+                    let height = min(child.height(&size), rect.height());
+                    (rect.y() + align.offset(height, rect.height()), height)
+                }
+                _ => (rect.y(), rect.height()),
+            };
+            let mut cbuffer =
+                buffer.subset(Rect::new(rect.x(), y, csize, height));
+            child.render(&mut cbuffer);
             buffer.merge(cbuffer);
             rect = rect.inner(Padding::left(csize));
         }
     }
 
-    /// Gets child sizes of vertical layout
-    fn ver_sizes(&self, rect: Rect) -> (Vec<usize>, Rect) {
+    /// Renders wrapping layout
+    fn wrap_render(&self, buffer: &mut Buffer, rect: Rect) {
+        let rects = self.wrap_rects(rect.size());
+        for (LayoutChild { child, .. }, crect) in
+            self.children.iter().zip(rects)
+        {
+            let crect =
+                Rect::from_coords(*rect.pos() + *crect.pos(), *crect.size());
+            let crect = crect.intersection(&rect);
+            if crect.width() == 0 || crect.height() == 0 {
+                continue;
+            }
+
+            let mut cbuffer = buffer.subset(crect);
+            child.render(&mut cbuffer);
+            buffer.merge(cbuffer);
+        }
+    }
+
+    /// Gets size of the wrapping layout content
+    fn wrap_size(&self, size: &Vec2) -> Vec2 {
+        self.wrap_rects(size)
+            .iter()
+            .fold(Vec2::new(0, 0), |acc, r| {
+                Vec2::new(
+                    max(acc.x, r.x() + r.width()),
+                    max(acc.y, r.y() + r.height()),
+                )
+            })
+    }
+
+    /// Gets rectangles of the children of the wrapping layout relative to
+    /// the layout position
+    fn wrap_rects(&self, size: &Vec2) -> Vec<Rect> {
+        let hor = matches!(self.direction, Direction::Horizontal);
+        // Converts primary and secondary axis values to the vector
+        let vec = |prim: usize, sec: usize| {
+            if hor {
+                Vec2::new(prim, sec)
+            } else {
+                Vec2::new(sec, prim)
+            }
+        };
+        let (prim, sec) = if hor {
+            (size.x, size.y)
+        } else {
+            (size.y, size.x)
+        };
+
+        let mut rects: Vec<Rect> = Vec::new();
+        let (mut pos, mut line_pos, mut line_size, mut line_start) =
+            (0, 0, 0, 0);
+        for LayoutChild { child, constraint } in self.children.iter() {
+            // Width is measured with the height the child needs, so the
+            // text children take their single line width when possible
+            let content = |s: &Vec2| {
+                if hor {
+                    child.width(&Vec2::new(s.x, child.height(s)))
+                } else {
+                    child.height(s)
+                }
+            };
+            let csize = match constraint {
+                Constraint::Length(len) => *len,
+                Constraint::Percent(p) => prim * p / 100,
+                Constraint::Min(l) => max(content(size), *l),
+                Constraint::Max(h) => min(content(size), *h),
+                Constraint::MinMax(l, h) => min(max(content(size), *l), *h),
+                Constraint::Fill(_) => content(size),
+            };
+            let csize = min(csize, prim);
+
+            if pos != 0 && pos + csize > prim {
+                Self::wrap_line(&mut rects[line_start..], line_size, hor);
+                line_pos += line_size;
+                (pos, line_size, line_start) = (0, 0, rects.len());
+            }
+
+            let csec = if hor {
+                child.height(&Vec2::new(csize, sec))
+            } else {
+                child.width(&Vec2::new(sec, csize))
+            };
+            rects
+                .push(Rect::from_coords(vec(pos, line_pos), vec(csize, csec)));
+            line_size = max(line_size, csec);
+            pos += csize;
+        }
+        Self::wrap_line(&mut rects[line_start..], line_size, hor);
+        rects
+    }
+
+    /// Stretches the children on the line of the wrapping layout to the size
+    /// of the line
+    fn wrap_line(rects: &mut [Rect], size: usize, hor: bool) {
+        for rect in rects {
+            let csize = if hor {
+                Vec2::new(rect.width(), size)
+            } else {
+                Vec2::new(size, rect.height())
+            };
+            *rect = Rect::from_coords(*rect.pos(), csize);
+        }
+    }
+
+    /// Gets child sizes and gaps before them of vertical layout
+    fn ver_sizes(&self, rect: Rect) -> (Vec<usize>, Vec<usize>) {
         self.child_sizes(
             rect,
             rect.height(),
             |c, s| c.height(s),
             |s, v| s.y = s.y.saturating_sub(v),
             |s| s.y,
-            |r, s| r.inner(Padding::vertical(s)),
         )
     }
 
-    /// Gets child sizes of horizontal layout
-    fn hor_sizes(&self, rect: Rect) -> (Vec<usize>, Rect) {
+    /// Gets child sizes and gaps before them of horizontal layout
+    fn hor_sizes(&self, rect: Rect) -> (Vec<usize>, Vec<usize>) {
         self.child_sizes(
             rect,
             rect.width(),
             |c, s| c.width(s),
             |s, v| s.x = s.x.saturating_sub(v),
             |s| s.x,
-            |r, s| r.inner(Padding::horizontal(s)),
         )
     }
 
-    /// Gets sizes of all the children
-    fn child_sizes<F1, F2, F3, F4>(
+    /// Gets sizes of all the children and sizes of the gaps before them
+    fn child_sizes<F1, F2, F3>(
         &self,
         rect: Rect,
         percent: usize,
         csize: F1,
         shrink: F2,
         left: F3,
-        inner: F4,
-    ) -> (Vec<usize>, Rect)
+    ) -> (Vec<usize>, Vec<usize>)
     where
-        F1: Fn(&Box<dyn Widget>, &Vec2) -> usize,
+        F1: Fn(&Element, &Vec2) -> usize,
         F2: Fn(&mut Vec2, usize),
         F3: Fn(Vec2) -> usize,
-        F4: Fn(Rect, usize) -> Rect,
     {
         let mut fill_ids = Vec::new();
         let mut fills = 0;
         let mut sizes = Vec::new();
-        let mut size = *rect.size();
+        // Content is measured using the whole layout size, so the measured
+        // size doesn't depend on the space taken by the previous children
+        // and matches the `height` and `width` functions
+        let full = *rect.size();
+        let mut size = full;
 
         for LayoutChild { child, constraint } in self.children.iter() {
             let csize = match constraint {
                 Constraint::Length(len) => *len,
                 Constraint::Percent(p) => percent * p / 100,
-                Constraint::Min(l) => max(csize(child, &size), *l),
-                Constraint::Max(h) => min(csize(child, &size), *h),
+                Constraint::Min(l) => max(csize(child, &full), *l),
+                Constraint::Max(h) => min(csize(child, &full), *h),
                 Constraint::MinMax(l, h) => {
-                    min(max(csize(child, &size), *l), *h)
+                    min(max(csize(child, &full), *l), *h)
                 }
                 Constraint::Fill(val) => {
                     fill_ids.push(sizes.len());
@@ -310,18 +578,49 @@ impl Layout {
             shrink(&mut size, csize);
         }
 
+        #[cfg(debug_assertions)]
+        if let Some(callback) = &self.on_overflow {
+            let needed: usize = sizes
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !fill_ids.contains(i))
+                .map(|(_, s)| s)
+                .sum();
+            if needed > percent {
+                callback(needed, percent);
+            }
+        }
+
         let mut left = left(size);
-        if fills == 0 && self.center {
-            return (sizes, inner(rect, left / 2));
+        if fills == 0 {
+            let gaps = self.gaps(sizes.len(), left);
+            return (sizes, gaps);
         }
 
         for f in fill_ids {
             let fill = sizes[f];
-            sizes[f] = left / fills * fill;
+            sizes[f] = (left * fill).div_ceil(fills);
             fills -= fill;
             left -= sizes[f];
         }
-        (sizes, rect)
+        let gaps = vec![0; sizes.len()];
+        (sizes, gaps)
+    }
+
+    /// Distributes the free space to the gaps before each of the children
+    /// based on the [`Justify`]
+    fn gaps(&self, cnt: usize, free: usize) -> Vec<usize> {
+        // Gets the free space before the child with given index
+        let before = |i: usize| match self.justify {
+            Justify::Start => 0,
+            Justify::SpaceBetween if cnt > 1 => free * i / (cnt - 1),
+            Justify::Center | Justify::SpaceBetween => free / 2,
+            Justify::SpaceAround => free * (2 * i + 1) / (2 * cnt),
+            Justify::SpaceEvenly => free * (i + 1) / (cnt + 1),
+        };
+        (0..cnt)
+            .map(|i| before(i) - if i == 0 { 0 } else { before(i - 1) })
+            .collect()
     }
 
     /// Renders [`Layout`] base style
@@ -336,7 +635,7 @@ impl Layout {
 
     fn size_sd<F>(&self, size: &Vec2, prim: usize, csize: F) -> usize
     where
-        F: Fn(&Box<dyn Widget>, &Vec2) -> usize,
+        F: Fn(&Element, &Vec2) -> usize,
     {
         let mut total = 0;
         let mut fill = false;
@@ -384,7 +683,7 @@ impl Layout {
 
         let mut left = Vec2::new(size.x, size.y.saturating_sub(total));
         for (child, f) in fills {
-            let h = left.y / total_fills * f;
+            let h = (left.y * f).div_ceil(total_fills);
             width = width.max(child.width(&left));
             left.y -= h;
             total_fills -= f;
@@ -418,7 +717,7 @@ impl Layout {
 
         let mut left = Vec2::new(size.x, size.y.saturating_sub(total));
         for (child, f) in fills {
-            let h = left.y / total_fills * f;
+            let h = (left.y * f).div_ceil(total_fills);
             height = height.max(child.width(&left));
             left.y -= h;
             total_fills -= f;
@@ -1,29 +1,33 @@
 use std::{
-    cell::RefCell,
-    cmp::{max, min},
+    cell::{Cell, RefCell},
+    cmp::max,
     rc::Rc,
 };
 
 use crate::{
     buffer::Buffer,
     enums::Color,
-    geometry::{Rect, Vec2},
+    geometry::{Direction, Rect, Vec2},
     style::Style,
-    text::Text,
+    text::{str_width, Text},
 };
 
-use super::{span::StrSpanExtension, widget::Widget, Element};
+use super::{
+    span::StrSpanExtension, widget::Widget, Element, Scrollbar, ScrollbarState,
+};
 
 /// List widget with scrollbar, that displays vector of strings
 ///
 /// ### Features:
-/// - Scrollbar (doesn't show when not necessary):
+/// - [`Scrollbar`] (doesn't show when not necessary):
 ///     - Scrollbar foreground
 ///     - Scrollbar thumb color
+///     - Any other [`Scrollbar`] customization using `scrollbar` function
 /// - Selected item:
 ///     - Foreground
 ///     - Background
 ///     - Character in front
+/// - Vertical or horizontal direction, see [`List::horizontal`]
 ///
 /// ## Example usage:
 /// ```rust
@@ -57,21 +61,27 @@ use super::{span::StrSpanExtension, widget::Widget, Element};
 #[derive(Debug)]
 pub struct List {
     items: Vec<String>,
+    styles: Vec<Option<Style>>,
     state: Rc<RefCell<ListState>>,
     auto_scroll: bool,
     style: Style,
     sel_style: Style,
     highlight: String,
     highlight_style: Style,
-    scrollbar_fg: Color,
-    thumb_fg: Color,
+    scrollbar: Scrollbar,
+    direction: Direction,
 }
 
 /// State of the [`List`] widget
+///
+/// When `filter` is set, only items containing it (case-insensitive) are
+/// shown and both `offset` and `selected` are indices into the shown items.
+/// Original indices can be obtained using [`List::visible_items`].
 #[derive(Debug)]
 pub struct ListState {
     pub offset: usize,
     pub selected: Option<usize>,
+    pub filter: Option<String>,
 }
 
 impl List {
@@ -81,10 +91,11 @@ impl List {
         T: IntoIterator,
         T::Item: AsRef<str>,
     {
-        let items =
+        let items: Vec<String> =
             items.into_iter().map(|i| i.as_ref().to_string()).collect();
 
         Self {
+            styles: vec![None; items.len()],
             items,
             state,
             auto_scroll: false,
@@ -92,8 +103,42 @@ impl List {
             sel_style: Default::default(),
             highlight: String::new(),
             highlight_style: Default::default(),
-            scrollbar_fg: Color::Default,
-            thumb_fg: Color::Default,
+            scrollbar: Scrollbar::vertical(Rc::new(Cell::new(
+                ScrollbarState::new(0),
+            ))),
+            direction: Direction::Vertical,
+        }
+    }
+
+    /// Creates new horizontal [`List`] with given items and given state.
+    ///
+    /// Items are in a single row, separated by a space, and the
+    /// [`Scrollbar`] is below them when they overflow and there's more than
+    /// one row available.
+    pub fn horizontal<T>(items: T, state: Rc<RefCell<ListState>>) -> Self
+    where
+        T: IntoIterator,
+        T::Item: AsRef<str>,
+    {
+        Self::new(items, state).direction(Direction::Horizontal)
+    }
+
+    /// Creates new [`List`] with given items, each with its own style, and
+    /// given state. Selected item style is applied on top of the item style
+    pub fn styled<T, S, I>(items: I, state: Rc<RefCell<ListState>>) -> Self
+    where
+        I: IntoIterator<Item = (T, S)>,
+        T: AsRef<str>,
+        S: Into<Style>,
+    {
+        let (items, styles): (Vec<_>, Vec<_>) = items
+            .into_iter()
+            .map(|(i, s)| (i.as_ref().to_string(), Some(s.into())))
+            .unzip();
+
+        Self {
+            styles,
+            ..Self::new(items, state)
         }
     }
 
@@ -106,6 +151,19 @@ impl List {
         self
     }
 
+    /// Sets direction in which the items of the [`List`] flow. This resets
+    /// the [`Scrollbar`] to the default one of the given direction, so it
+    /// should be set before customizing the scrollbar.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        let state = Rc::new(Cell::new(ScrollbarState::new(0)));
+        self.direction = direction;
+        self.scrollbar = match direction {
+            Direction::Vertical => Scrollbar::vertical(state),
+            Direction::Horizontal => Scrollbar::horizontal(state),
+        };
+        self
+    }
+
     /// Automatically scrolls so the selected item is visible
     pub fn auto_scroll(mut self) -> Self {
         self.auto_scroll = true;
@@ -151,15 +209,42 @@ impl List {
 
     /// Sets [`List`] scrollbar color
     pub fn scrollbar_fg(mut self, fg: Color) -> Self {
-        self.scrollbar_fg = fg;
+        self.scrollbar = self.scrollbar.track_style(fg);
         self
     }
 
     /// Sets [`List`] scrollbar thumb color
     pub fn thumb_fg(mut self, fg: Color) -> Self {
-        self.thumb_fg = fg;
+        self.scrollbar = self.scrollbar.thumb_style(fg);
+        self
+    }
+
+    /// Sets the [`Scrollbar`] used by the [`List`]. Its state is set by the
+    /// [`List`] when rendering and its direction always matches the
+    /// direction of the [`List`].
+    pub fn scrollbar(mut self, scrollbar: Scrollbar) -> Self {
+        self.scrollbar = scrollbar.direction(self.direction);
         self
     }
+
+    /// Gets original indices of the items shown with the current filter
+    pub fn visible_items(&self) -> Vec<usize> {
+        let state = self.state.borrow();
+        let Some(filter) = &state.filter else {
+            return (0..self.items.len()).collect();
+        };
+
+        let filter = filter.to_lowercase();
+        (0..self.items.len())
+            .filter(|i| self.items[*i].to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    /// Gets original index of the selected item
+    pub fn selected_item(&self) -> Option<usize> {
+        let selected = self.state.borrow().selected?;
+        self.visible_items().get(selected).copied()
+    }
 }
 
 impl ListState {
@@ -168,6 +253,7 @@ impl ListState {
         Self {
             offset,
             selected: None,
+            filter: None,
         }
     }
 
@@ -176,36 +262,94 @@ impl ListState {
         Self {
             offset,
             selected: Some(selected),
+            filter: None,
         }
     }
+
+    /// Sets filter of the shown items, `None` shows all the items
+    pub fn filter<T>(mut self, filter: T) -> Self
+    where
+        T: Into<Option<String>>,
+    {
+        self.filter = filter.into();
+        self
+    }
 }
 
 impl Widget for List {
     fn render(&self, buffer: &mut Buffer) {
+        match self.direction {
+            Direction::Vertical => self.ver_render(buffer),
+            Direction::Horizontal => self.hor_render(buffer),
+        }
+    }
+
+    fn height(&self, size: &Vec2) -> usize {
+        if self.direction == Direction::Horizontal {
+            return if self.fits(&self.filtered(), size) {
+                1
+            } else {
+                2
+            };
+        }
+
+        let mut height = 0;
+        for item in self.filtered() {
+            height += item.to_span().height(size);
+        }
+        height
+    }
+
+    fn width(&self, size: &Vec2) -> usize {
+        if self.direction == Direction::Horizontal {
+            return self.content_size(&self.filtered(), size);
+        }
+
+        let mut width = 0;
+        for item in self.filtered() {
+            let span = item.to_span();
+            width = max(span.width(size), width);
+        }
+        width + 1
+    }
+}
+
+impl List {
+    /// Renders the vertical [`List`]
+    fn ver_render(&self, buffer: &mut Buffer) {
+        let ids = self.visible_items();
+        let items = self.filtered();
         if self.auto_scroll {
-            self.scroll_offset(buffer.size());
+            self.scroll_offset(&items, buffer.size());
         }
 
         let mut text_pos =
-            Vec2::new(buffer.x() + self.highlight.len(), buffer.y());
-        let mut text_size =
-            Vec2::new(buffer.width() - self.highlight.len(), buffer.height());
+            Vec2::new(buffer.x() + str_width(&self.highlight), buffer.y());
+        let mut text_size = Vec2::new(
+            buffer.width() - str_width(&self.highlight),
+            buffer.height(),
+        );
 
-        if !self.fits(buffer.size()) {
+        if !self.fits(&items, buffer.size()) {
             text_size.x -= 1;
-            self.render_scrollbar(buffer);
+            self.render_scrollbar(&items, buffer);
         }
 
         let selected = self.state.borrow().selected;
-        for i in self.state.borrow().offset..self.items.len() {
-            let mut span = self.items[i].style(self.style);
+        let offset = self.state.borrow().offset;
+        for (i, item) in items.iter().enumerate().skip(offset) {
+            let style = self.styles[ids[i]];
+            let mut span = item.style(style.unwrap_or(self.style));
             if Some(i) == selected {
                 buffer.set_str_styled(
                     &self.highlight,
                     &Vec2::new(buffer.x(), text_pos.y),
                     self.highlight_style,
                 );
-                span = self.items[i].style(self.sel_style);
+                span = item.style(match style {
+                    Some(style) => style.merge(&self.sel_style),
+                    None => self.sel_style,
+                });
             }
 
             let mut ibuffer =
@@ -223,89 +367,185 @@ impl Widget for List {
         }
     }
 
-    fn height(&self, size: &Vec2) -> usize {
-        let mut height = 0;
-        for i in 0..self.items.len() {
-            let span = self.items[i].to_span();
-            height += span.height(size);
+    /// Renders the horizontal [`List`]
+    fn hor_render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
         }
-        height
-    }
 
-    fn width(&self, size: &Vec2) -> usize {
-        let mut width = 0;
-        for item in self.items.iter() {
-            let span = item.to_span();
-            width = max(span.width(size), width);
+        let ids = self.visible_items();
+        let items = self.filtered();
+        if self.auto_scroll {
+            self.scroll_offset(&items, buffer.size());
         }
-        width + 1
-    }
-}
-
-impl List {
-    /// Renders [`List`] scrollbar
-    fn render_scrollbar(&self, buffer: &mut Buffer) {
-        let rat = self.items.len() as f32 / buffer.height() as f32;
-        let thumb_size = min(
-            (buffer.height() as f32 / rat).floor() as usize,
-            buffer.height(),
-        );
-        let thumb_offset = min(
-            (self.state.borrow().offset as f32 / rat) as usize,
-            buffer.height() - thumb_size,
-        );
 
-        let x = (buffer.x() + buffer.width()).saturating_sub(1);
-        let mut bar_pos = Vec2::new(x, buffer.y());
-        for _ in 0..buffer.height() {
-            buffer.set_val('│', &bar_pos);
-            buffer.set_fg(self.scrollbar_fg, &bar_pos);
-            bar_pos.y += 1;
+        if buffer.height() > 1 && !self.fits(&items, buffer.size()) {
+            self.render_scrollbar(&items, buffer);
         }
 
-        bar_pos = Vec2::new(x, buffer.y() + thumb_offset);
-        for _ in 0..thumb_size {
-            buffer.set_val('┃', &bar_pos);
-            buffer.set_fg(self.thumb_fg, &bar_pos);
-            bar_pos.y += 1;
+        let selected = self.state.borrow().selected;
+        let offset = self.state.borrow().offset;
+        let end = buffer.x() + buffer.width();
+        let hl_width = str_width(&self.highlight);
+        let mut x = buffer.x();
+        for (i, item) in items.iter().enumerate().skip(offset) {
+            if x >= end {
+                break;
+            }
+
+            let style = self.styles[ids[i]];
+            let mut span = item.style(style.unwrap_or(self.style));
+            if Some(i) == selected {
+                let width = hl_width.min(end - x);
+                let mut hbuffer =
+                    buffer.subset(Rect::new(x, buffer.y(), width, 1));
+                hbuffer.set_str_styled(
+                    &self.highlight,
+                    &Vec2::new(x, buffer.y()),
+                    self.highlight_style,
+                );
+                buffer.merge(hbuffer);
+                span = item.style(match style {
+                    Some(style) => style.merge(&self.sel_style),
+                    None => self.sel_style,
+                });
+            }
+
+            let text_x = x + hl_width;
+            let width = str_width(item).min(end.saturating_sub(text_x));
+            if width > 0 {
+                let mut ibuffer =
+                    buffer.subset(Rect::new(text_x, buffer.y(), width, 1));
+                span.render(&mut ibuffer);
+                buffer.merge(ibuffer);
+            }
+            x += self.item_size(item, buffer.size()) + self.gap();
         }
     }
 
+    /// Renders [`List`] scrollbar. Vertical scrollbar scrolls by items,
+    /// horizontal one by cells, since the items have different widths
+    fn render_scrollbar(&self, items: &[&str], buffer: &mut Buffer) {
+        let offset = self.state.borrow().offset;
+        let rect = match self.direction {
+            Direction::Vertical => {
+                self.scrollbar.content_len(items.len());
+                self.scrollbar.offset(offset);
+
+                let x = (buffer.x() + buffer.width()).saturating_sub(1);
+                Rect::new(x, buffer.y(), 1, buffer.height())
+            }
+            Direction::Horizontal => {
+                let size = buffer.size();
+                let skipped: usize = items
+                    .iter()
+                    .take(offset)
+                    .map(|i| self.item_size(i, size) + self.gap())
+                    .sum();
+                self.scrollbar.content_len(self.content_size(items, size));
+                self.scrollbar.offset(skipped);
+
+                let y = (buffer.y() + buffer.height()).saturating_sub(1);
+                Rect::new(buffer.x(), y, buffer.width(), 1)
+            }
+        };
+
+        let mut sbuffer = buffer.subset(rect);
+        self.scrollbar.render(&mut sbuffer);
+        buffer.merge(sbuffer);
+    }
+
+    /// Gets items shown with the current filter
+    fn filtered(&self) -> Vec<&str> {
+        self.visible_items()
+            .into_iter()
+            .map(|i| self.items[i].as_str())
+            .collect()
+    }
+
     /// Automatically scrolls so the selected item is visible
-    fn scroll_offset(&self, size: &Vec2) {
+    fn scroll_offset(&self, items: &[&str], size: &Vec2) {
         let Some(selected) = self.state.borrow().selected else {
             return;
         };
+        if selected >= items.len() {
+            return;
+        }
 
         if selected < self.state.borrow().offset {
             self.state.borrow_mut().offset = selected;
             return;
         }
 
-        while !self.is_visible(selected, self.state.borrow().offset, size) {
+        // Item starting at the offset is visible even when it's clipped,
+        // which stops scrolling past items bigger than the list
+        loop {
+            let offset = self.state.borrow().offset;
+            if offset >= selected
+                || self.is_visible(items, selected, offset, size)
+            {
+                break;
+            }
             self.state.borrow_mut().offset += 1;
         }
     }
 
     /// Checks if item is visible with given offset
-    fn is_visible(&self, item: usize, offset: usize, size: &Vec2) -> bool {
-        let mut height = 0;
-        for i in offset..self.items.len() {
-            height += self.items[i].to_span().height(size);
-            if height > size.y {
+    fn is_visible(
+        &self,
+        items: &[&str],
+        item: usize,
+        offset: usize,
+        size: &Vec2,
+    ) -> bool {
+        let space = match self.direction {
+            Direction::Vertical => size.y,
+            Direction::Horizontal => size.x,
+        };
+
+        let mut used = 0;
+        for (i, text) in items.iter().enumerate().skip(offset) {
+            used += self.item_size(text, size);
+            if used > space {
                 return false;
             }
 
             if i == item {
                 return true;
             }
+            used += self.gap();
         }
         false
     }
 
     /// Checks if list fits to the visible area
-    fn fits(&self, size: &Vec2) -> bool {
-        self.is_visible(self.items.len() - 1, 0, size)
+    fn fits(&self, items: &[&str], size: &Vec2) -> bool {
+        items.is_empty() || self.is_visible(items, items.len() - 1, 0, size)
+    }
+
+    /// Gets size of the item along the direction of the [`List`]
+    fn item_size(&self, item: &str, size: &Vec2) -> usize {
+        match self.direction {
+            Direction::Vertical => item.to_span().height(size),
+            Direction::Horizontal => {
+                str_width(&self.highlight) + str_width(item)
+            }
+        }
+    }
+
+    /// Gets gap between the items along the direction of the [`List`]
+    fn gap(&self) -> usize {
+        match self.direction {
+            Direction::Vertical => 0,
+            Direction::Horizontal => 1,
+        }
+    }
+
+    /// Gets size of the given items along the direction of the [`List`],
+    /// including the gaps between them
+    fn content_size(&self, items: &[&str], size: &Vec2) -> usize {
+        let gaps = items.len().saturating_sub(1) * self.gap();
+        items.iter().map(|i| self.item_size(i, size)).sum::<usize>() + gaps
     }
 }
 
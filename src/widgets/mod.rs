@@ -1,23 +1,50 @@
 //! `widgets` is collection of types that implement `Widget` trait
 //!
 //! Available widgets:
+//! - [`BarChart`]: widget drawing vertical bars with labels
 //! - [`Block`]: [`Layout`] widget with addition of optional border, title
 //!     and styles
+//! - [`Calendar`]: widget displaying month with selectable date
+//! - [`Canvas`]: widget for drawing points, lines and rectangles
 //! - [`Center`]: widget for centering other widget
+//! - [`Chart`]: widget plotting series of points using braille characters
+//! - [`Empty`]: widget rendering nothing, placeholder for no widget
+//! - [`Gauge`]: widget filling its area by ratio with centered label
 //! - [`Grad`]: widget that draws text with gradient foreground
 //! - [`Layout`]: widget for creating layouts
 //! - [`List`]: widget creating list layout with scrollbar
+//! - [`Padded`]: widget adding padding around other widget
 //! - [`Paragraph`]: widget rendering continous text using widgets implementing
 //!     `Text` trait
+//! - [`Sparkline`]: widget displaying trend of values on a single row
 //! - [`Spacer`]: widget for creating spaces between widgets (better layouting)
 //! - [`Span`]: widget for styling text
+//! - [`Spinner`]: widget cycling through frames as a loading indicator
+//! - [`Tabs`]: widget rendering tab titles with highlighted selected tab
+//! - [`TextArea`]: widget for multi-line text editing
+//! - [`TextInput`]: widget for single-line text editing
+//! - [`Tree`]: widget displaying hierarchy of collapsible nodes
 
+/// Bar chart widget drawing vertical bars
+mod bar_chart;
 /// [`Layout`] widget with gradient background
 mod bg_grad;
 /// [`Layout`] widget with border around it
 mod block;
 /// Border sides definition and border type enum
 mod border;
+/// Calendar widget displaying month with selectable date
+mod calendar;
+/// Canvas widget for drawing shapes
+mod canvas;
+/// Widget centering other widget
+mod center;
+/// Chart widget plotting points using braille characters
+mod chart;
+/// Empty widget rendering nothing
+mod empty;
+/// Gauge widget with centered label
+mod gauge;
 /// Text with gradient foreground
 mod grad;
 /// Creates layout by specifying columns and rows
@@ -28,6 +55,8 @@ mod layout;
 mod list;
 /// Widget that stack its children on top of each other
 mod overlay;
+/// Widget adding padding around other widget
+mod padded;
 /// Chaining widgets implementing [`Text`] trait
 mod paragraph;
 /// Widget that uses scrollbar for overflown content
@@ -38,17 +67,47 @@ mod scrollbar;
 mod spacer;
 /// Widget for styling text
 mod span;
+/// Sparkline widget displaying trend of values
+mod sparkline;
+/// Spinner widget used as a loading indicator
+mod spinner;
+/// Tabs widget for switching between panels
+mod tabs;
+/// Multi-line text editing widget
+mod text_area;
+/// Single-line text input widget
+mod text_input;
+/// Tree widget with collapsible nodes
+mod tree;
 /// Trait for widgets to implement
 mod widget;
 
+/// Bar chart widget drawing vertical bars
+pub use bar_chart::BarChart;
 /// [`Layout`] widget with gradient background
 pub use bg_grad::BgGrad;
 /// [`Layout`] widget with border around it
 pub use block::Block;
 /// Border sides definition
 pub use border::Border;
+/// Set of characters for custom border type
+pub use border::BorderSet;
 /// Border type enum
 pub use border::BorderType;
+/// Calendar widget displaying month with selectable date
+pub use calendar::*;
+/// Canvas widget for drawing shapes
+pub use canvas::Canvas;
+/// Draws into the canvas area
+pub use canvas::Painter;
+/// Widget centering other widget
+pub use center::Center;
+/// Chart widget plotting points using braille characters
+pub use chart::Chart;
+/// Empty widget rendering nothing
+pub use empty::Empty;
+/// Gauge widget with centered label
+pub use gauge::Gauge;
 /// Text with gradient foreground
 pub use grad::Grad;
 /// Creates layout by specifying columns and rows
@@ -61,6 +120,8 @@ pub use list::List;
 pub use list::ListState;
 /// Widget that stack its children on top of each other
 pub use overlay::*;
+/// Widget adding padding around other widget
+pub use padded::Padded;
 /// Chaining widgets implementing [`Text`] trait
 pub use paragraph::Paragraph;
 /// Widget that uses scrollbar for overflown content
@@ -73,5 +134,21 @@ pub use spacer::Spacer;
 pub use span::Span;
 /// Enables better string conversion to [`Span`]
 pub use span::StrSpanExtension;
+/// Sparkline widget displaying trend of values
+pub use sparkline::Sparkline;
+/// Spinner widget used as a loading indicator
+pub use spinner::Spinner;
+/// Tabs widget for switching between panels
+pub use tabs::Tabs;
+/// Multi-line text editing widget
+pub use text_area::TextArea;
+/// Single-line text input widget
+pub use text_input::TextInput;
+/// Tree widget with collapsible nodes
+pub use tree::Tree;
+/// Node of the tree widget
+pub use tree::TreeNode;
+/// State of the tree widget
+pub use tree::TreeState;
 /// Trait for widgets to implemen
 pub use widget::*;
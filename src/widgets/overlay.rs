@@ -1,9 +1,18 @@
-use crate::{buffer::Buffer, geometry::Vec2};
+use std::cmp::{max, min};
+
+use crate::{
+    buffer::Buffer,
+    geometry::{Align, Constraint, Rect, Vec2},
+    style::Style,
+};
 
 use super::{Element, Widget};
 
 /// Stacks children in layers, first child is at the bottom, last on top
 ///
+/// Layers can optionally be named using an id, which allows accessing them
+/// after the [`Overlay`] was created (for example to toggle a popup).
+///
 /// ## Example usage:
 /// ```rust
 /// # use termint::{
@@ -13,45 +22,215 @@ use super::{Element, Widget};
 /// # };
 /// # fn get_bottom_child() -> Element { Spacer::new().into() }
 /// # fn get_top_child() -> Element { Spacer::new().into() }
-/// let overlay = Overlay::new(vec![
+/// # fn get_popup() -> Element { Spacer::new().into() }
+/// let mut overlay = Overlay::new(vec![
 ///     get_bottom_child(),
 ///     get_top_child(),
 /// ]);
 ///
+/// // Adds named layer, which can be later accessed by its id
+/// overlay.push_layer("popup", get_popup());
+///
 /// let mut buffer = Buffer::empty(Rect::new(1, 1, 20, 10));
 /// overlay.render(&mut buffer);
 /// buffer.render();
+///
+/// // Removes the popup layer
+/// overlay.remove_layer("popup");
+/// ```
+///
+/// Children can also be aligned within the [`Overlay`], which is useful for
+/// modal dialogs:
+/// ```rust
+/// # use termint::{
+/// #     geometry::Align,
+/// #     widgets::{Block, Overlay},
+/// # };
+/// let mut overlay = Overlay::new(vec![Block::vertical().into()]);
+///
+/// // Centered 30x5 confirmation dialog
+/// let dialog = Block::vertical().title("Confirm");
+/// let center = Align::Center;
+/// overlay.push_aligned(dialog, (30, 5), center, center);
 /// ```
+///
+/// To make the top child (e.g. the dialog) stand out, the content below it
+/// can be dimmed using [`Overlay::dim_below`] and it can cast a shadow using
+/// [`Overlay::shadow`].
 pub struct Overlay {
-    children: Vec<Element>,
+    children: Vec<OverlayChild>,
+    dim: Option<Style>,
+    shadow: Option<Style>,
+}
+
+/// Contains overlay child and its optional layer id
+struct OverlayChild {
+    pub child: Element,
+    pub id: Option<String>,
+    pub align: Option<OverlayAlign>,
+}
+
+/// Size constraints and alignment of the aligned overlay child
+struct OverlayAlign {
+    pub width: Constraint,
+    pub height: Constraint,
+    pub h_align: Align,
+    pub v_align: Align,
 }
 
 impl Overlay {
     /// Creates new [`Overlay`] with given children
     pub fn new(children: Vec<Element>) -> Self {
         Self {
-            children: children.into_iter().map(|c| c.into()).collect(),
+            children: children
+                .into_iter()
+                .map(|child| OverlayChild {
+                    child,
+                    id: None,
+                    align: None,
+                })
+                .collect(),
+            dim: None,
+            shadow: None,
         }
     }
 
+    /// Sets style applied to the whole [`Overlay`] area before rendering the
+    /// top child, which dims the content below it. Only the style of the
+    /// cells is changed, their characters are kept
+    pub fn dim_below<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.dim = Some(style.into());
+        self
+    }
+
+    /// Sets style of the drop shadow of the top child. The shadow is its
+    /// area offset by one cell right and down, drawn below it by changing
+    /// the style of the cells, not their characters
+    pub fn shadow<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.shadow = Some(style.into());
+        self
+    }
+
     /// Pushes child to the [`Overlay`]
     pub fn push<W>(&mut self, child: W)
     where
         W: Into<Element>,
     {
-        self.children.push(child.into());
+        self.children.push(OverlayChild {
+            child: child.into(),
+            id: None,
+            align: None,
+        });
+    }
+
+    /// Pushes child to the [`Overlay`] with given width and height
+    /// constraints, aligned within the [`Overlay`] using horizontal and
+    /// vertical alignment. When child is larger than the [`Overlay`], it is
+    /// clamped to the [`Overlay`] origin.
+    pub fn push_aligned<W, C, D>(
+        &mut self,
+        child: W,
+        (width, height): (C, D),
+        h_align: Align,
+        v_align: Align,
+    ) where
+        W: Into<Element>,
+        C: Into<Constraint>,
+        D: Into<Constraint>,
+    {
+        self.children.push(OverlayChild {
+            child: child.into(),
+            id: None,
+            align: Some(OverlayAlign {
+                width: width.into(),
+                height: height.into(),
+                h_align,
+                v_align,
+            }),
+        });
+    }
+
+    /// Pushes child as a layer with given id to the [`Overlay`]. If layer
+    /// with the same id already exists, it is replaced and keeps its position
+    pub fn push_layer<T, W>(&mut self, id: T, child: W)
+    where
+        T: Into<String>,
+        W: Into<Element>,
+    {
+        let id = id.into();
+        match self.get_layer_mut(&id) {
+            Some(layer) => *layer = child.into(),
+            None => self.children.push(OverlayChild {
+                child: child.into(),
+                id: Some(id),
+                align: None,
+            }),
+        }
+    }
+
+    /// Gets reference to the layer with given id
+    pub fn get_layer(&self, id: &str) -> Option<&Element> {
+        self.children
+            .iter()
+            .find(|c| c.id.as_deref() == Some(id))
+            .map(|c| &c.child)
+    }
+
+    /// Gets mutable reference to the layer with given id
+    pub fn get_layer_mut(&mut self, id: &str) -> Option<&mut Element> {
+        self.children
+            .iter_mut()
+            .find(|c| c.id.as_deref() == Some(id))
+            .map(|c| &mut c.child)
+    }
+
+    /// Removes layer with given id from the [`Overlay`] and returns it
+    pub fn remove_layer(&mut self, id: &str) -> Option<Element> {
+        let pos = self
+            .children
+            .iter()
+            .position(|c| c.id.as_deref() == Some(id))?;
+        Some(self.children.remove(pos).child)
     }
 }
 
 impl Widget for Overlay {
     fn render(&self, buffer: &mut Buffer) {
-        self.children.iter().for_each(|c| c.render(buffer));
+        let top = self.children.len().saturating_sub(1);
+        for (i, OverlayChild { child, align, .. }) in
+            self.children.iter().enumerate()
+        {
+            let rect = match align {
+                Some(align) => align.rect(child, buffer.rect()),
+                None => *buffer.rect(),
+            };
+            if i == top {
+                self.render_effects(buffer, rect);
+            }
+
+            if align.is_none() {
+                child.render(buffer);
+                continue;
+            }
+            if rect.is_empty() {
+                continue;
+            }
+            let mut cbuffer = buffer.subset(rect);
+            child.render(&mut cbuffer);
+            buffer.merge(cbuffer);
+        }
     }
 
     fn height(&self, size: &Vec2) -> usize {
         self.children
             .iter()
-            .map(|c| c.height(size))
+            .map(|c| c.child.height(size))
             .max()
             .unwrap_or(0)
     }
@@ -59,10 +238,60 @@ impl Widget for Overlay {
     fn width(&self, size: &Vec2) -> usize {
         self.children
             .iter()
-            .map(|c| c.width(size))
+            .map(|c| c.child.width(size))
             .max()
             .unwrap_or(0)
     }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        self.children.iter().map(|c| c.child.as_widget()).collect()
+    }
+}
+
+impl Overlay {
+    /// Dims the content and draws shadow of the top child with given
+    /// [`Rect`]
+    fn render_effects(&self, buffer: &mut Buffer, rect: Rect) {
+        if let Some(dim) = self.dim {
+            buffer.fill_style(*buffer.rect(), dim);
+        }
+        if let Some(shadow) = self.shadow.filter(|_| !rect.is_empty()) {
+            let pos = Vec2::new(rect.x() + 1, rect.y() + 1);
+            buffer.fill_style(Rect::from_coords(pos, *rect.size()), shadow);
+        }
+    }
+}
+
+impl OverlayAlign {
+    /// Gets [`Rect`] of the given child aligned within the given [`Rect`]
+    fn rect(&self, child: &Element, parent: &Rect) -> Rect {
+        let width = Self::size(self.width, parent.width(), || {
+            child.width(parent.size())
+        });
+        let height = Self::size(self.height, parent.height(), || {
+            child.height(&Vec2::new(width, parent.height()))
+        });
+
+        let x = self.h_align.offset(width, parent.width());
+        let y = self.v_align.offset(height, parent.height());
+        Rect::new(parent.x() + x, parent.y() + y, width, height)
+    }
+
+    /// Gets size based on the constraint, clamped to the parent size
+    fn size<F>(constraint: Constraint, parent: usize, child: F) -> usize
+    where
+        F: Fn() -> usize,
+    {
+        let size = match constraint {
+            Constraint::Length(len) => len,
+            Constraint::Percent(p) => parent * p / 100,
+            Constraint::Min(l) => max(child(), l),
+            Constraint::Max(h) => min(child(), h),
+            Constraint::MinMax(l, h) => min(max(child(), l), h),
+            Constraint::Fill(_) => parent,
+        };
+        min(size, parent)
+    }
 }
 
 impl From<Overlay> for Element {
@@ -0,0 +1,104 @@
+use crate::{
+    buffer::Buffer,
+    geometry::{Padding, Vec2},
+};
+
+use super::{Element, Widget};
+
+/// Wraps widget and adds [`Padding`] around it, without the need of
+/// bordered [`Block`](super::Block)
+///
+/// ## Example usage:
+/// ```rust
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     geometry::Rect,
+/// #     widgets::{Padded, StrSpanExtension, Widget},
+/// # };
+/// // Adds one cell of padding on the top and bottom and two on the sides
+/// let padded = Padded::new("Padded text".to_span(), (1, 2));
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 15, 3));
+/// padded.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct Padded<W = Element> {
+    child: W,
+    padding: Padding,
+}
+
+impl<W> Padded<W>
+where
+    W: Widget,
+{
+    /// Creates new [`Padded`] wrapping given widget with given [`Padding`]
+    pub fn new<P>(child: W, padding: P) -> Self
+    where
+        P: Into<Padding>,
+    {
+        Self {
+            child,
+            padding: padding.into(),
+        }
+    }
+}
+
+impl<W> Widget for Padded<W>
+where
+    W: Widget,
+{
+    fn render(&self, buffer: &mut Buffer) {
+        let rect = buffer.rect().inner(self.padding);
+        if rect.width() == 0 || rect.height() == 0 {
+            return;
+        }
+
+        let mut cbuffer = buffer.subset(rect);
+        self.child.render(&mut cbuffer);
+        buffer.merge(cbuffer);
+    }
+
+    fn height(&self, size: &Vec2) -> usize {
+        let size = self.inner_size(size);
+        self.child.height(&size) + self.padding.get_vertical()
+    }
+
+    fn width(&self, size: &Vec2) -> usize {
+        let size = self.inner_size(size);
+        self.child.width(&size) + self.padding.get_horizontal()
+    }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![&self.child]
+    }
+}
+
+impl<W> Padded<W> {
+    /// Gets size available for the child
+    fn inner_size(&self, size: &Vec2) -> Vec2 {
+        Vec2::new(
+            size.x.saturating_sub(self.padding.get_horizontal()),
+            size.y.saturating_sub(self.padding.get_vertical()),
+        )
+    }
+}
+
+// From implementations
+impl<W> From<Padded<W>> for Box<dyn Widget>
+where
+    W: Widget + 'static,
+{
+    fn from(value: Padded<W>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<W> From<Padded<W>> for Element
+where
+    W: Widget + 'static,
+{
+    fn from(value: Padded<W>) -> Self {
+        Element::new(value)
+    }
+}
@@ -1,10 +1,10 @@
 use core::fmt;
 
 use crate::{
-    buffer::Buffer,
+    buffer::{Buffer, Cell},
     enums::Wrap,
-    geometry::{Rect, Vec2},
-    text::Text,
+    geometry::{Rect, TextAlign, Vec2},
+    text::{str_width, Text},
 };
 
 use super::{widget::Widget, Element};
@@ -55,6 +55,7 @@ pub struct Paragraph {
     children: Vec<Box<dyn Text>>,
     separator: String,
     wrap: Wrap,
+    align: TextAlign,
 }
 
 impl Paragraph {
@@ -95,6 +96,13 @@ impl Paragraph {
         self
     }
 
+    /// Sets [`Paragraph`] alignment of each line to given value. Justify
+    /// isn't supported and aligns the lines left
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
     /// Adds child to [`Paragraph`]
     pub fn add<T>(&mut self, child: T)
     where
@@ -106,31 +114,12 @@ impl Paragraph {
 
 impl Widget for Paragraph {
     fn render(&self, buffer: &mut Buffer) {
-        let mut pos = Vec2::new(buffer.x(), buffer.y());
-        let mut size = Vec2::new(buffer.width(), buffer.height());
-        let mut offset = 0;
-
-        for child in self.children.iter() {
-            let mut cbuffer = buffer.subset(Rect::from_coords(pos, size));
-            let end =
-                child.render_offset(&mut cbuffer, offset, Some(self.wrap));
-            buffer.merge(cbuffer);
-
-            size.y = size.y.saturating_sub(end.y - pos.y);
-            pos.y = end.y;
-            offset = end.x + self.separator.len();
-
-            if end.y >= buffer.y() + buffer.height()
-                && end.x >= buffer.x() + buffer.width()
-            {
-                break;
-            }
-
-            if offset + self.separator.len() <= buffer.width() && offset != 0 {
-                buffer.set_str(
-                    &self.separator,
-                    &Vec2::new(buffer.x() + offset - 1, pos.y),
-                );
+        match self.align {
+            TextAlign::Left | TextAlign::Justify => self.render_lines(buffer),
+            _ => {
+                let mut lines = Buffer::empty(*buffer.rect());
+                self.render_lines(&mut lines);
+                self.align_lines(buffer, &lines);
             }
         }
     }
@@ -163,40 +152,125 @@ impl Default for Paragraph {
             children: Vec::new(),
             separator: " ".to_string(),
             wrap: Wrap::Word,
+            align: Default::default(),
         }
     }
 }
 
 impl Paragraph {
-    /// Gets [`Paragraph`] height when using word wrap
+    /// Renders children of the [`Paragraph`] after each other, with lines
+    /// aligned left
+    fn render_lines(&self, buffer: &mut Buffer) {
+        let mut pos = Vec2::new(buffer.x(), buffer.y());
+        let mut size = Vec2::new(buffer.width(), buffer.height());
+        let mut offset = 0;
+
+        for child in self.children.iter() {
+            let mut cbuffer = buffer.subset(Rect::from_coords(pos, size));
+            let end =
+                child.render_offset(&mut cbuffer, offset, Some(self.wrap));
+            buffer.merge(cbuffer);
+
+            size.y = size.y.saturating_sub(end.y - pos.y);
+            pos.y = end.y;
+            offset = end.x + str_width(&self.separator);
+
+            if end.y >= buffer.y() + buffer.height()
+                && end.x >= buffer.x() + buffer.width()
+            {
+                break;
+            }
+
+            if offset + str_width(&self.separator) <= buffer.width()
+                && offset != 0
+            {
+                buffer.set_str(
+                    &self.separator,
+                    &Vec2::new(buffer.x() + offset - 1, pos.y),
+                );
+            }
+        }
+    }
+
+    /// Copies lines from given buffer rendered aligned left to the buffer,
+    /// moving each line based on its width and the set alignment
+    fn align_lines(&self, buffer: &mut Buffer, lines: &Buffer) {
+        let empty = Cell::default();
+        for y in lines.y()..lines.y() + lines.height() {
+            let width = (0..lines.width())
+                .rev()
+                .find(|x| lines[(lines.x() + x, y)] != empty)
+                .map_or(0, |x| x + 1);
+            let shift = match self.align {
+                TextAlign::Center => (lines.width() - width) >> 1,
+                TextAlign::Right => lines.width() - width,
+                _ => 0,
+            };
+
+            for x in lines.x()..lines.x() + width {
+                buffer[(x + shift, y)] = lines[(x, y)].clone();
+            }
+        }
+    }
+
+    /// Gets [`Paragraph`] height when using word wrap. It follows the same
+    /// wrapping as rendering does: each child starts after the separator
+    /// following the previous child and each newline starts a new line
     fn height_word_wrap(&self, size: &Vec2) -> usize {
         let mut coords = Vec2::new(0, 0);
+        let sep = str_width(&self.separator);
 
-        for child in self.children.iter() {
-            let words: Vec<&str> =
-                child.get_text().split_whitespace().collect();
-            for word in words {
-                if (coords.x == 0 && coords.x + word.len() > size.x)
-                    || (coords.x != 0 && coords.x + word.len() + 1 > size.x)
-                {
-                    coords.y += 1;
-                    coords.x = 0;
-                }
+        for (i, child) in self.children.iter().enumerate() {
+            if i != 0 {
+                coords.x += sep;
+            }
 
-                if coords.x != 0 {
-                    coords.x += 1;
+            for (j, line) in child.get_text().lines().enumerate() {
+                if j != 0 {
+                    (coords.x, coords.y) = (0, coords.y + 1);
                 }
-                coords.x += word.len();
+                Self::line_word_wrap(&**child, line, &mut coords, size.x);
             }
         }
         coords.y + 1
     }
 
+    /// Moves given coords by one line of text when using word wrap. Word
+    /// longer than the width ends the line with ellipsis, since rendering
+    /// truncates the rest of the line
+    fn line_word_wrap(
+        child: &dyn Text,
+        line: &str,
+        coords: &mut Vec2,
+        width: usize,
+    ) {
+        let mut start = coords.x;
+        let mut empty = true;
+        for word in line.split_whitespace() {
+            let len = str_width(word);
+            if coords.x + len + !empty as usize > width {
+                if len > width {
+                    coords.x =
+                        child.ellipsis_end(start, coords.x - start, width);
+                    return;
+                }
+                (coords.x, coords.y) = (0, coords.y + 1);
+                start = 0;
+                empty = true;
+            }
+            coords.x += len + !empty as usize;
+            empty = false;
+        }
+    }
+
     /// Gets width of [`Paragraph`] when using word wrap
     fn width_word_wrap(&self, size: &Vec2) -> usize {
+        let max = self.size_letter_wrap(1)
+            + self.children.len().saturating_sub(1)
+                * str_width(&self.separator);
         let mut guess = Vec2::new(self.size_letter_wrap(size.y), 0);
 
-        while self.height_word_wrap(&guess) > size.y {
+        while guess.x < max && self.height_word_wrap(&guess) > size.y {
             guess.x += 1;
         }
         guess.x
@@ -207,7 +281,7 @@ impl Paragraph {
     fn size_letter_wrap(&self, size: usize) -> usize {
         let mut len = 0;
         for child in self.children.iter() {
-            len += child.get_text().len();
+            len += child.display_width();
         }
         (len as f32 / size as f32).ceil() as usize
     }
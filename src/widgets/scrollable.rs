@@ -131,6 +131,10 @@ where
             (false, false) => self.child.width(size),
         }
     }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![&self.child]
+    }
 }
 
 impl<W> Scrollable<W>
@@ -2,7 +2,7 @@ use std::{cell::Cell, rc::Rc};
 
 use crate::{
     buffer::Buffer,
-    geometry::{Direction, Vec2, Vec2Range},
+    geometry::{Direction, Rect, Vec2, Vec2Range},
     style::Style,
 };
 
@@ -15,6 +15,9 @@ use super::{Element, Widget};
 /// only while rendering. For example, `Scrollable` widget uses the scrollbar
 /// and sets the `content_len` before rendering the scrollbar.
 ///
+/// The thumb is always at least one cell big, so it stays visible no matter
+/// how long the content is.
+///
 /// ## Example usage:
 /// ```rust
 /// # use std::{cell::Cell, rc::Rc};
@@ -120,6 +123,46 @@ impl Scrollbar {
     pub fn get_state(&self) -> ScrollbarState {
         self.state.get()
     }
+
+    /// Gets content offset corresponding to the click at given position
+    /// inside the [`Rect`] the [`Scrollbar`] was rendered in.
+    ///
+    /// Clicking on the thumb maps the click position to the offset (which
+    /// allows dragging the thumb), clicking above or below the thumb scrolls
+    /// by one page, except for the first and the last cell of the track,
+    /// which jump to the start and the end. The result is clamped to
+    /// `content_len - visible` and it's 0 when all the content is visible.
+    pub fn offset_at(&self, click: Vec2, rect: Rect) -> usize {
+        let (click, start, visible) = match self.direction {
+            Direction::Vertical => (click.y, rect.y(), rect.height()),
+            Direction::Horizontal => (click.x, rect.x(), rect.width()),
+        };
+        if visible == 0 || self.state.get().content_len <= visible {
+            return 0;
+        }
+        let Some((size, pos)) = self.calc_thumb(visible) else {
+            return 0;
+        };
+
+        let offset = self.state.get().offset;
+        let max_offset = self.state.get().content_len.saturating_sub(visible);
+        let click = click.saturating_sub(start).min(visible - 1);
+        if click < pos && click == 0 {
+            0
+        } else if click < pos {
+            offset.saturating_sub(visible)
+        } else if click >= pos + size && click + 1 == visible {
+            max_offset
+        } else if click >= pos + size {
+            (offset + visible).min(max_offset)
+        } else if size >= visible {
+            offset
+        } else {
+            let free = visible - size;
+            let track = click.saturating_sub(size / 2).min(free);
+            (track as f64 / free as f64 * max_offset as f64).round() as usize
+        }
+    }
 }
 
 impl ScrollbarState {
@@ -155,6 +198,20 @@ impl ScrollbarState {
         self.offset = self.offset.saturating_sub(1);
     }
 
+    /// Scrolls down by one page of given viewport size. The offset is
+    /// clamped to `content_len - viewport`, so the last page stays full,
+    /// but it's never moved back (e.g. after [`ScrollbarState::last`])
+    pub fn page_down(&mut self, viewport: usize) {
+        let max_offset = self.content_len.saturating_sub(viewport);
+        self.offset =
+            (self.offset + viewport).min(max_offset.max(self.offset));
+    }
+
+    /// Scrolls up by one page of given viewport size
+    pub fn page_up(&mut self, viewport: usize) {
+        self.offset = self.offset.saturating_sub(viewport);
+    }
+
     /// Sets the scroll offset to the first position
     pub fn first(&mut self) {
         self.offset = 0;
@@ -168,6 +225,10 @@ impl ScrollbarState {
 
 impl Widget for Scrollbar {
     fn render(&self, buffer: &mut Buffer) {
+        if buffer.width() == 0 || buffer.height() == 0 {
+            return;
+        }
+
         match self.direction {
             Direction::Vertical => self.ver_render(buffer),
             Direction::Horizontal => self.hor_render(buffer),
@@ -226,15 +287,17 @@ impl Scrollbar {
         self.render_thumb(buffer, start.to(end));
     }
 
-    /// Gets size of the thumb and its position
+    /// Gets size of the thumb and its position. Thumb is always at least
+    /// one cell big, so it stays visible even for very long content
     fn calc_thumb(&self, visible: usize) -> Option<(usize, usize)> {
         let total = self.state.get().content_len;
-        if total <= visible {
+        if visible == 0 || total <= visible {
             return None;
         }
 
         let thumb_size =
             ((visible * visible) as f64 / total as f64).round() as usize;
+        let thumb_size = thumb_size.max(1).min(visible);
         let max_offset = total.saturating_sub(visible);
 
         let mut state = self.state.get();
@@ -253,16 +316,20 @@ impl Scrollbar {
     /// Renders the scrollbar track
     fn render_track(&self, buffer: &mut Buffer, pos_range: Vec2Range) {
         for pos in pos_range {
-            buffer[pos] =
-                buffer[pos].val(self.track_char).style(self.track_style);
+            buffer[pos] = buffer[pos]
+                .clone()
+                .val(self.track_char)
+                .style(self.track_style);
         }
     }
 
     /// Renders the scrollbar thumb
     fn render_thumb(&self, buffer: &mut Buffer, pos_range: Vec2Range) {
         for pos in pos_range {
-            buffer[pos] =
-                buffer[pos].val(self.thumb_char).style(self.thumb_style);
+            buffer[pos] = buffer[pos]
+                .clone()
+                .val(self.thumb_char)
+                .style(self.thumb_style);
         }
     }
 }
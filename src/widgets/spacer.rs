@@ -1,10 +1,15 @@
-use crate::{buffer::Buffer, geometry::Vec2};
+use crate::{buffer::Buffer, geometry::Vec2, style::Style};
 
 use super::{widget::Widget, Element};
 
 /// Spacer widget for better layouting
 ///
 /// Can be used to add spaces to the layout, for example, between two widget.
+/// When you want no widget at all (e.g. conditional rendering), use
+/// [`Empty`](super::Empty) instead, which isn't meant to take any space.
+///
+/// By default nothing is rendered, but the space can be filled with
+/// character (such as dotted leader between label and value) and style.
 ///
 /// ## Example usage:
 /// ```rust
@@ -22,17 +27,49 @@ use super::{widget::Widget, Element};
 /// layout.add_child("One space above", Constraint::Min(0));
 /// ```
 #[derive(Debug, Default)]
-pub struct Spacer {}
+pub struct Spacer {
+    fill: Option<char>,
+    style: Option<Style>,
+}
 
 impl Spacer {
     /// Creates new spacer
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Sets character the [`Spacer`] area is filled with
+    pub fn fill_char(mut self, fill: char) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Sets style the [`Spacer`] area is painted with
+    pub fn style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.style = Some(style.into());
+        self
     }
 }
 
 impl Widget for Spacer {
-    fn render(&self, _buffer: &mut Buffer) {}
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 || (self.fill.is_none() && self.style.is_none())
+        {
+            return;
+        }
+
+        for pos in buffer.rect().into_iter() {
+            if let Some(fill) = self.fill {
+                buffer.set_val(fill, &pos);
+            }
+            if let Some(style) = self.style {
+                buffer.set_style(style, &pos);
+            }
+        }
+    }
 
     fn height(&self, _size: &Vec2) -> usize {
         0
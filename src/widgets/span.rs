@@ -1,11 +1,11 @@
 use std::fmt;
 
 use crate::{
-    buffer::Buffer,
-    enums::{Color, Wrap},
+    buffer::{Buffer, Link},
+    enums::{Color, EllipsisMode, Wrap},
     geometry::{TextAlign, Vec2},
     style::Style,
-    text::{Text, TextParser, TextToken},
+    text::{default_ellipsis, prefix, str_width, suffix, Text, TextParser},
 };
 
 use super::{widget::Widget, Element};
@@ -19,7 +19,10 @@ use super::{widget::Widget, Element};
 /// - align: can be set using [`TextAlign`]
 /// - wrap: how text should be wrapped, can be set using [`Wrap`]
 /// - ellipsis: indication of overflown text, can be set to any string
-///     (default: '...')
+///   (default: '...', can be changed using [`set_default_ellipsis`])
+/// - link: hyperlink the text points to, rendered using OSC 8 escape code
+///
+/// [`set_default_ellipsis`]: crate::text::set_default_ellipsis
 ///
 /// ## Example usage:
 /// ```rust
@@ -60,6 +63,8 @@ pub struct Span {
     align: TextAlign,
     wrap: Wrap,
     ellipsis: String,
+    ellipsis_mode: EllipsisMode,
+    link: Option<Link>,
 }
 
 impl Span {
@@ -102,19 +107,19 @@ impl Span {
     }
 
     /// Sets [`Span`] modifier to given modifier
-    pub fn modifier(mut self, modifier: u8) -> Self {
+    pub fn modifier(mut self, modifier: u16) -> Self {
         self.style = self.style.modifier(modifier);
         self
     }
 
     /// Sets modifiers of [`Span`] to given modifiers
-    pub fn add_modifier(mut self, flag: u8) -> Self {
+    pub fn add_modifier(mut self, flag: u16) -> Self {
         self.style = self.style.add_modifier(flag);
         self
     }
 
     /// Removes given modifier from [`Span`] modifiers
-    pub fn remove_modifier(mut self, flag: u8) -> Self {
+    pub fn remove_modifier(mut self, flag: u16) -> Self {
         self.style = self.style.remove_modifier(flag);
         self
     }
@@ -136,6 +141,20 @@ impl Span {
         self.ellipsis = ellipsis.into();
         self
     }
+
+    /// Sets where the [`Span`] is truncated when it overflows on its last
+    /// visible row. [`EllipsisMode::Start`] and [`EllipsisMode::Middle`]
+    /// keep the end of the row's remaining text, cutting through words
+    pub fn ellipsis_mode(mut self, mode: EllipsisMode) -> Self {
+        self.ellipsis_mode = mode;
+        self
+    }
+
+    /// Sets hyperlink of the [`Span`] to given URL
+    pub fn link<T: AsRef<str>>(mut self, url: T) -> Self {
+        self.link = Some(Link::new(url));
+        self
+    }
 }
 
 impl Widget for Span {
@@ -149,7 +168,7 @@ impl Widget for Span {
 
     fn height(&self, size: &Vec2) -> usize {
         match self.wrap {
-            Wrap::Letter => self.size_letter_wrap(size.x),
+            Wrap::Letter => self.height_letter_wrap(size.x),
             Wrap::Word => self.height_word_wrap(size),
         }
     }
@@ -183,7 +202,24 @@ impl Text for Span {
     }
 
     fn get(&self) -> String {
-        format!("{}{}\x1b[0m", self.get_mods(), self.text)
+        match &self.link {
+            Some(link) => format!(
+                "{}{}{}{}\x1b[0m",
+                self.get_mods(),
+                link.start(),
+                self.text,
+                Link::end()
+            ),
+            None => format!("{}{}\x1b[0m", self.get_mods(), self.text),
+        }
+    }
+
+    fn ellipsis_end(&self, start: usize, len: usize, width: usize) -> usize {
+        if self.ellipsis_mode != EllipsisMode::End {
+            return width;
+        }
+        let ellipsis = str_width(&self.ellipsis);
+        start + ellipsis + len.min(width.saturating_sub(ellipsis + start))
     }
 
     fn get_text(&self) -> &str {
@@ -202,7 +238,9 @@ impl Default for Span {
             style: Default::default(),
             align: Default::default(),
             wrap: Default::default(),
-            ellipsis: "...".to_string(),
+            ellipsis: default_ellipsis(),
+            ellipsis_mode: Default::default(),
+            link: None,
         }
     }
 }
@@ -244,22 +282,42 @@ impl Span {
 
     fn render_words(&self, buffer: &mut Buffer) {
         let mut chars = self.text.chars();
-        let mut parser = TextParser::new(&mut chars);
+        let mut parser = TextParser::new(&mut chars).word_break(true);
 
         let mut pos = Vec2::new(buffer.x(), buffer.y());
-        let bottom = buffer.bottom();
-        loop {
-            match parser.next_line(buffer.width()) {
-                TextToken::Text { text, len } => {
-                    self.render_line2(buffer, text, len, &pos);
-                }
-                TextToken::Newline => pos.y += 1,
-                TextToken::End => break,
+        while let Some((words, len)) = parser.next_line_words(buffer.width()) {
+            if self.align == TextAlign::Justify
+                && parser.wrapped()
+                && words.len() > 1
+            {
+                self.render_justified(buffer, words, len, &pos);
+            } else {
+                self.render_line2(buffer, words.join(" "), len, &pos);
             }
             pos.y += 1;
         }
     }
 
+    /// Renders one line of text with spaces distributed between the words,
+    /// so that the line takes the whole width
+    fn render_justified(
+        &self,
+        buffer: &mut Buffer,
+        words: Vec<String>,
+        len: usize,
+        pos: &Vec2,
+    ) {
+        let gaps = words.len() - 1;
+        let extra = buffer.width().saturating_sub(len);
+
+        let mut x = pos.x;
+        for (i, word) in words.into_iter().enumerate() {
+            let width = str_width(&word);
+            self.set_str(buffer, word, &Vec2::new(x, pos.y));
+            x += width + 1 + extra / gaps + (i < extra % gaps) as usize;
+        }
+    }
+
     /// Renders one line of text and aligns it based on set alignment
     fn render_line2(
         &self,
@@ -269,11 +327,11 @@ impl Span {
         pos: &Vec2,
     ) {
         let x = match self.align {
-            TextAlign::Left => 0,
+            TextAlign::Left | TextAlign::Justify => 0,
             TextAlign::Center => buffer.width().saturating_sub(len) >> 1,
             TextAlign::Right => buffer.width().saturating_sub(len),
         };
-        buffer.set_str_styled(line, &Vec2::new(pos.x + x, pos.y), self.style);
+        self.set_str(buffer, line, &Vec2::new(pos.x + x, pos.y));
     }
 
     /// Renders [`Span`] with word wrapping with given offset
@@ -288,24 +346,43 @@ impl Span {
         let mut line = Vec::<&str>::new();
         let mut coords = Vec2::new(offset_x, offset_y);
 
-        for word in text.split_whitespace() {
-            let word_len = word.chars().count();
+        let mut words = text.split_whitespace();
+        while let Some(word) = words.next() {
+            let word_len = str_width(word);
             if coords.x + word_len + !line.is_empty() as usize > buffer.width()
             {
                 if coords.y + 1 >= buffer.y() + buffer.height()
                     || word_len > buffer.width()
                 {
+                    if self.ellipsis_mode != EllipsisMode::End {
+                        let rest: Vec<_> = line
+                            .iter()
+                            .copied()
+                            .chain([word])
+                            .chain(words)
+                            .collect();
+                        let width = buffer.width().saturating_sub(offset_x);
+                        let line_str = self.truncate(&rest.join(" "), width);
+                        coords.x = str_width(&line_str) + offset_x;
+                        self.render_line(
+                            buffer,
+                            line_str,
+                            &Vec2::new(buffer.x() + offset_x, coords.y),
+                        );
+                        return coords;
+                    }
+
                     let mut line_str = line.join(" ");
-                    let sum = coords.x + self.ellipsis.len() + offset_x;
+                    let sum = coords.x + str_width(&self.ellipsis) + offset_x;
                     if sum >= buffer.width() {
-                        let end = buffer
-                            .width()
-                            .saturating_sub(self.ellipsis.len() + offset_x);
-                        line_str = line_str[..end].to_string();
+                        let end = buffer.width().saturating_sub(
+                            str_width(&self.ellipsis) + offset_x,
+                        );
+                        line_str = line_str.chars().take(end).collect();
                     }
 
                     line_str.push_str(&self.ellipsis);
-                    coords.x = line_str.len() + offset_x;
+                    coords.x = str_width(&line_str) + offset_x;
                     self.render_line(
                         buffer,
                         line_str,
@@ -347,37 +424,114 @@ impl Span {
         offset_x: usize,
         offset_y: usize,
     ) -> Vec2 {
-        let stext: String = text.chars().take(buffer.area()).collect();
-        buffer.set_str_styled(
-            &stext,
+        let space = buffer.area().saturating_sub(offset_x);
+        if self.ellipsis_mode != EllipsisMode::End && str_width(text) > space {
+            let stext = self.truncate(text, space);
+            self.set_str(
+                buffer,
+                &stext,
+                &Vec2::new(buffer.x() + offset_x, offset_y),
+            );
+            return Self::end_pos(buffer, str_width(&stext) + offset_x);
+        }
+
+        let len = str_width(text);
+        let ellipsis = str_width(&self.ellipsis);
+        let stext = match len <= space {
+            true => text,
+            false => prefix(text, space.saturating_sub(ellipsis)),
+        };
+        self.set_str(
+            buffer,
+            stext,
             &Vec2::new(buffer.x() + offset_x, offset_y),
-            self.style,
         );
 
-        if stext.len() != text.len() && !self.ellipsis.is_empty() {
+        if len <= space {
+            return Self::end_pos(buffer, len + offset_x);
+        }
+
+        if !self.ellipsis.is_empty() {
             let coords = Vec2::new(
-                (buffer.x() + buffer.width())
-                    .saturating_sub(self.ellipsis.len()),
+                (buffer.x() + buffer.width()).saturating_sub(ellipsis),
                 (buffer.y() + buffer.height()).saturating_sub(1),
             );
-            buffer.set_str_styled(&self.ellipsis, &coords, self.style)
+            self.set_str(buffer, &self.ellipsis, &coords)
         }
+        Self::end_pos(buffer, buffer.area())
+    }
 
-        buffer.pos_of(stext.len() + offset_x)
+    /// Gets position where letter wrapped text of given length ends, with
+    /// x coordinate relative to the buffer, same as when using word wrap.
+    /// Text filling the whole buffer ends after the end of its last row
+    fn end_pos(buffer: &Buffer, len: usize) -> Vec2 {
+        if len >= buffer.area() {
+            let y = (buffer.y() + buffer.height()).saturating_sub(1);
+            return Vec2::new(buffer.width(), y);
+        }
+        let pos = buffer.pos_of(len);
+        Vec2::new(pos.x - buffer.x(), pos.y)
+    }
+
+    /// Truncates given text to fit the given width, placing the ellipsis
+    /// based on the [`EllipsisMode`]
+    fn truncate(&self, text: &str, width: usize) -> String {
+        if str_width(text) <= width {
+            return text.to_string();
+        }
+
+        let ellipsis = str_width(&self.ellipsis);
+        if ellipsis >= width {
+            return prefix(&self.ellipsis, width).to_string();
+        }
+
+        let space = width - ellipsis;
+        let (head, tail) = match self.ellipsis_mode {
+            EllipsisMode::End => (space, 0),
+            EllipsisMode::Start => (0, space),
+            EllipsisMode::Middle => (space - space / 2, space / 2),
+        };
+        format!(
+            "{}{}{}",
+            prefix(text, head),
+            self.ellipsis,
+            suffix(text, tail)
+        )
     }
 
     /// Renders one line of text and aligns it based on set alignment
     fn render_line(&self, buffer: &mut Buffer, line: String, pos: &Vec2) {
         let x = match self.align {
-            TextAlign::Left => 0,
+            TextAlign::Left | TextAlign::Justify => 0,
             TextAlign::Center => {
-                buffer.width().saturating_sub(line.chars().count()) >> 1
+                buffer.width().saturating_sub(str_width(&line)) >> 1
             }
             TextAlign::Right => {
-                buffer.width().saturating_sub(line.chars().count())
+                buffer.width().saturating_sub(str_width(&line))
             }
         };
-        buffer.set_str_styled(line, &Vec2::new(pos.x + x, pos.y), self.style);
+        self.set_str(buffer, line, &Vec2::new(pos.x + x, pos.y));
+    }
+
+    /// Sets styled string to the buffer on given position, including the
+    /// hyperlink when set
+    fn set_str<T: AsRef<str>>(
+        &self,
+        buffer: &mut Buffer,
+        text: T,
+        pos: &Vec2,
+    ) {
+        let text = text.as_ref();
+        buffer.set_str_styled(text, pos, self.style);
+        if self.link.is_none() {
+            return;
+        }
+
+        let id = buffer.index_of(pos);
+        let end = (id + str_width(text)).min(buffer.area());
+        for i in id..end {
+            buffer.set_link(self.link.clone(), &buffer.pos_of(i));
+        }
     }
 
     /// Gets height of the [`Span`] when using word wrap
@@ -386,10 +540,8 @@ impl Span {
 
         let words: Vec<&str> = self.text.split_whitespace().collect();
         for word in words {
-            let len = word.len();
-            if (coords.x == 0 && coords.x + len > size.x)
-                || (coords.x != 0 && coords.x + len + 1 > size.x)
-            {
+            let len = str_width(word);
+            if coords.x != 0 && coords.x + len + 1 > size.x {
                 coords.y += 1;
                 coords.x = 0;
             }
@@ -398,6 +550,12 @@ impl Span {
                 coords.x += 1;
             }
             coords.x += len;
+
+            // Words longer than the line are broken into multiple lines
+            if size.x != 0 && coords.x > size.x {
+                coords.y += (coords.x - 1) / size.x;
+                coords.x = (coords.x - 1) % size.x + 1;
+            }
         }
         coords.y + 1
     }
@@ -412,9 +570,22 @@ impl Span {
         guess.x
     }
 
+    /// Gets height of the [`Span`] when using letter wrap. Lines are
+    /// counted the same way as they're rendered, so each line takes at least
+    /// one row and the trailing newline doesn't add an empty row
+    fn height_letter_wrap(&self, width: usize) -> usize {
+        if width == 0 {
+            return 0;
+        }
+        self.text
+            .lines()
+            .map(|l| str_width(l).div_ceil(width).max(1))
+            .sum()
+    }
+
     /// Gets size of the [`Span`] when using letter wrap
     fn size_letter_wrap(&self, size: usize) -> usize {
-        (self.text.len() as f32 / size as f32).ceil() as usize
+        (str_width(&self.text) as f32 / size as f32).ceil() as usize
     }
 }
 
@@ -436,10 +607,10 @@ pub trait StrSpanExtension {
         T: Into<Option<Color>>;
 
     /// Creates [`Span`] from string and sets its modifier to given value
-    fn modifier(self, modifier: u8) -> Span;
+    fn modifier(self, modifier: u16) -> Span;
 
     /// Creates [`Span`] from string and add given modifier to it
-    fn add_modifier(self, flag: u8) -> Span;
+    fn add_modifier(self, flag: u16) -> Span;
 
     /// Creates [`Span`] from string and sets its alignment to given value
     fn align(self, align: TextAlign) -> Span;
@@ -452,11 +623,17 @@ pub trait StrSpanExtension {
     where
         T: AsRef<str>;
 
-    /// Converts &str to [`Span`]
+    /// Creates [`Span`] from string and sets its ellipsis mode to given value
+    fn ellipsis_mode(self, mode: EllipsisMode) -> Span;
+
+    /// Converts string to [`Span`]
     fn to_span(self) -> Span;
 }
 
-impl StrSpanExtension for &str {
+impl<S> StrSpanExtension for S
+where
+    S: AsRef<str>,
+{
     fn style<T>(self, style: T) -> Span
     where
         T: Into<Style>,
@@ -478,11 +655,11 @@ impl StrSpanExtension for &str {
         Span::new(self).bg(bg)
     }
 
-    fn modifier(self, modifier: u8) -> Span {
+    fn modifier(self, modifier: u16) -> Span {
         Span::new(self).modifier(modifier)
     }
 
-    fn add_modifier(self, flag: u8) -> Span {
+    fn add_modifier(self, flag: u16) -> Span {
         Span::new(self).add_modifier(flag)
     }
 
@@ -501,6 +678,10 @@ impl StrSpanExtension for &str {
         Span::new(self).ellipsis(ellipsis.as_ref())
     }
 
+    fn ellipsis_mode(self, mode: EllipsisMode) -> Span {
+        Span::new(self).ellipsis_mode(mode)
+    }
+
     fn to_span(self) -> Span {
         Span::new(self)
     }
@@ -0,0 +1,122 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{buffer::Buffer, geometry::Vec2, style::Style};
+
+use super::{bar_chart::BARS, widget::Widget, Element};
+
+/// Sparkline widget displaying trend of the values on a single row
+///
+/// Values are scaled between their minimum and maximum (which can be pinned
+/// using [`Sparkline::max`]). When there are more values than columns, the
+/// most recent ones are shown, when there are fewer, they're left-padded.
+///
+/// ## Example usage:
+/// ```rust
+/// # use std::{cell::RefCell, rc::Rc};
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::Rect,
+/// #     widgets::{Sparkline, Widget},
+/// # };
+/// let data = Rc::new(RefCell::new(vec![1, 5, 3, 8, 2]));
+/// let sparkline = Sparkline::shared(data.clone()).style(Color::Green);
+///
+/// // New values can be pushed without recreating the widget
+/// data.borrow_mut().push(6);
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 10, 1));
+/// sparkline.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct Sparkline {
+    data: Rc<RefCell<Vec<u64>>>,
+    max: Option<u64>,
+    style: Style,
+}
+
+impl Sparkline {
+    /// Creates new [`Sparkline`] with given values
+    pub fn new(data: &[u64]) -> Self {
+        Self::shared(Rc::new(RefCell::new(data.to_vec())))
+    }
+
+    /// Creates new [`Sparkline`] with shared values
+    pub fn shared(data: Rc<RefCell<Vec<u64>>>) -> Self {
+        Self {
+            data,
+            max: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the value at the top of the scale. When `None`, maximum of the
+    /// shown values is used
+    pub fn max<T>(mut self, max: T) -> Self
+    where
+        T: Into<Option<u64>>,
+    {
+        self.max = max.into();
+        self
+    }
+
+    /// Sets style of the [`Sparkline`]
+    pub fn style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Widget for Sparkline {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let data = self.data.borrow();
+        let data = &data[data.len().saturating_sub(buffer.width())..];
+        let min = data.iter().copied().min().unwrap_or(0);
+        let max = self.max.or_else(|| data.iter().copied().max()).unwrap_or(0);
+        let range = max.saturating_sub(min) as u128;
+
+        let mut pos =
+            Vec2::new(buffer.x() + buffer.width() - data.len(), buffer.y());
+        for value in data {
+            let id = match range {
+                0 => 0,
+                _ => {
+                    let val = value.saturating_sub(min).min(max - min);
+                    val as u128 * (BARS.len() - 1) as u128 / range
+                }
+            };
+            buffer.set_val(BARS[id as usize], &pos);
+            buffer.set_style(self.style, &pos);
+            pos.x += 1;
+        }
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        1
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        self.data.borrow().len()
+    }
+}
+
+// From implementations
+impl From<Sparkline> for Box<dyn Widget> {
+    fn from(value: Sparkline) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Sparkline> for Element {
+    fn from(value: Sparkline) -> Self {
+        Element::new(value)
+    }
+}
@@ -0,0 +1,123 @@
+use std::{cell::Cell, rc::Rc};
+
+use crate::{buffer::Buffer, geometry::Vec2, style::Style, text::char_width};
+
+use super::{widget::Widget, Element};
+
+/// Spinner widget cycling through frames, used as a loading indicator
+///
+/// Current frame is picked by the tick shared using `Rc<Cell<usize>>`, so
+/// the spinner is animated by advancing the tick (e.g. on each update).
+///
+/// ## Example usage:
+/// ```rust
+/// # use std::{cell::Cell, rc::Rc};
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::Rect,
+/// #     widgets::{Spinner, Widget},
+/// # };
+/// let tick = Rc::new(Cell::new(0));
+/// let spinner = Spinner::new(tick.clone())
+///     .frames(Spinner::LINE)
+///     .style(Color::Cyan);
+///
+/// // Advances the spinner to the next frame
+/// tick.set(tick.get() + 1);
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 1, 1));
+/// spinner.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct Spinner {
+    tick: Rc<Cell<usize>>,
+    frames: Vec<char>,
+    style: Style,
+}
+
+impl Spinner {
+    /// Braille dots frames, used by default
+    pub const DOTS: &'static [char] =
+        &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    /// Rotating line frames
+    pub const LINE: &'static [char] = &['|', '/', '-', '\\'];
+    /// Rotating arrow frames
+    pub const ARROWS: &'static [char] =
+        &['←', '↖', '↑', '↗', '→', '↘', '↓', '↙'];
+
+    /// Creates new [`Spinner`] with given tick and [`Spinner::DOTS`] frames
+    pub fn new(tick: Rc<Cell<usize>>) -> Self {
+        Self {
+            tick,
+            frames: Self::DOTS.to_vec(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets frames the [`Spinner`] cycles through
+    pub fn frames<T>(mut self, frames: T) -> Self
+    where
+        T: Into<Vec<char>>,
+    {
+        self.frames = frames.into();
+        self
+    }
+
+    /// Sets style of the [`Spinner`]
+    pub fn style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Gets frame corresponding to the current tick
+    pub fn frame(&self) -> Option<char> {
+        if self.frames.is_empty() {
+            return None;
+        }
+        Some(self.frames[self.tick.get() % self.frames.len()])
+    }
+}
+
+impl Widget for Spinner {
+    fn render(&self, buffer: &mut Buffer) {
+        let Some(frame) = self.frame() else {
+            return;
+        };
+        if buffer.width() < char_width(frame) || buffer.height() == 0 {
+            return;
+        }
+
+        let pos = *buffer.pos();
+        buffer.set_str_styled(frame.to_string(), &pos, self.style);
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        1
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        self.frames
+            .iter()
+            .map(|f| char_width(*f))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+// From implementations
+impl From<Spinner> for Box<dyn Widget> {
+    fn from(value: Spinner) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Spinner> for Element {
+    fn from(value: Spinner) -> Self {
+        Element::new(value)
+    }
+}
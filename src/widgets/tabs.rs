@@ -0,0 +1,188 @@
+use std::{cell::Cell, rc::Rc};
+
+use crate::{
+    buffer::Buffer,
+    geometry::Vec2,
+    style::Style,
+    text::{char_width, default_ellipsis, str_width},
+};
+
+use super::{widget::Widget, Element};
+
+/// Tabs widget rendering titles separated by divider on a single row, with
+/// the selected tab highlighted
+///
+/// Index of the selected tab is shared using `Rc<Cell<usize>>`, so it can be
+/// changed (for example on key press) without recreating the widget. When
+/// the titles overflow, they're truncated and ellipsis is added.
+///
+/// ## Example usage:
+/// ```rust
+/// # use std::{cell::Cell, rc::Rc};
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::Rect,
+/// #     widgets::{Tabs, Widget},
+/// # };
+/// let selected = Rc::new(Cell::new(0));
+/// let titles = vec!["Files", "Search", "Settings"];
+/// let tabs = Tabs::new(titles, selected.clone())
+///     .divider('|')
+///     .selected_style(Color::Yellow);
+///
+/// // Selects the next tab
+/// selected.set(1);
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 22, 1));
+/// tabs.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct Tabs {
+    titles: Vec<String>,
+    selected: Rc<Cell<usize>>,
+    divider: char,
+    style: Style,
+    sel_style: Style,
+    ellipsis: String,
+}
+
+impl Tabs {
+    /// Creates new [`Tabs`] with given titles and selected tab index
+    pub fn new<T>(titles: Vec<T>, selected: Rc<Cell<usize>>) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            titles: titles.into_iter().map(|t| t.into()).collect(),
+            selected,
+            divider: '│',
+            style: Default::default(),
+            sel_style: Default::default(),
+            ellipsis: default_ellipsis(),
+        }
+    }
+
+    /// Sets the character dividing the [`Tabs`] titles
+    pub fn divider(mut self, divider: char) -> Self {
+        self.divider = divider;
+        self
+    }
+
+    /// Sets style of the [`Tabs`] titles and dividers
+    pub fn style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets style of the selected tab title
+    pub fn selected_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.sel_style = style.into();
+        self
+    }
+
+    /// Sets [`Tabs`] ellipsis to given string
+    pub fn ellipsis(mut self, ellipsis: &str) -> Self {
+        self.ellipsis = ellipsis.to_string();
+        self
+    }
+
+    /// Gets index of the selected tab
+    pub fn get_selected(&self) -> usize {
+        self.selected.get()
+    }
+}
+
+impl Widget for Tabs {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let overflow = self.width(buffer.size()) > buffer.width();
+        let mut left = if overflow {
+            buffer.width().saturating_sub(str_width(&self.ellipsis))
+        } else {
+            buffer.width()
+        };
+
+        let selected = self.selected.get();
+        let mut pos = *buffer.pos();
+        for (i, title) in self.titles.iter().enumerate() {
+            if i != 0 {
+                let width = char_width(self.divider);
+                if width > left {
+                    break;
+                }
+                buffer.set_val(self.divider, &pos);
+                buffer.set_style(self.style, &pos);
+                pos.x += width;
+                left -= width;
+            }
+
+            let style = if i == selected {
+                self.sel_style
+            } else {
+                self.style
+            };
+            let text = Self::fit(title, left);
+            let width = str_width(&text);
+            buffer.set_str_styled(text, &pos, style);
+            pos.x += width;
+            left -= width;
+
+            if width < str_width(title) {
+                break;
+            }
+        }
+
+        if overflow {
+            let left = buffer.width() - (pos.x - buffer.x());
+            let ellipsis = Self::fit(&self.ellipsis, left);
+            buffer.set_str_styled(ellipsis, &pos, self.style);
+        }
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        1
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        let titles: usize = self.titles.iter().map(|t| str_width(t)).sum();
+        let dividers = self.titles.len().saturating_sub(1);
+        titles + dividers * char_width(self.divider)
+    }
+}
+
+impl Tabs {
+    /// Gets longest prefix of the given text fitting into given width
+    fn fit(text: &str, width: usize) -> String {
+        let mut len = 0;
+        text.chars()
+            .take_while(|c| {
+                len += char_width(*c);
+                len <= width
+            })
+            .collect()
+    }
+}
+
+// From implementations
+impl From<Tabs> for Box<dyn Widget> {
+    fn from(value: Tabs) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Tabs> for Element {
+    fn from(value: Tabs) -> Self {
+        Element::new(value)
+    }
+}
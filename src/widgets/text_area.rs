@@ -0,0 +1,350 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use crate::{
+    buffer::Buffer, enums::Modifier, geometry::Vec2, style::Style,
+    text::char_width,
+};
+
+use super::{widget::Widget, Element};
+
+/// Multi-line text editing widget with cursor
+///
+/// Lines and the cursor position (`x` is index of the character in the line
+/// and `y` is index of the line) are shared, so they can be edited from the
+/// event handler using the [`TextArea`] functions or directly. The content is
+/// scrolled, so the cursor stays visible. Tabs are expanded to spaces.
+///
+/// ## Example usage:
+/// ```rust
+/// # use std::{cell::{Cell, RefCell}, rc::Rc};
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     geometry::{Rect, Vec2},
+/// #     widgets::{TextArea, Widget},
+/// # };
+/// let lines = Rc::new(RefCell::new(vec![String::from("Notes")]));
+/// let cursor = Rc::new(Cell::new(Vec2::new(5, 0)));
+/// let area = TextArea::new(lines.clone(), cursor.clone()).tab_width(2);
+///
+/// // Edits the text, for example on key press
+/// area.insert_newline();
+/// area.insert_char('-');
+/// assert_eq!(*lines.borrow(), vec!["Notes", "-"]);
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 20, 5));
+/// area.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct TextArea {
+    lines: Rc<RefCell<Vec<String>>>,
+    cursor: Rc<Cell<Vec2>>,
+    offset: Cell<Vec2>,
+    tab_width: usize,
+    style: Style,
+    cursor_style: Style,
+    term_cursor: bool,
+}
+
+impl TextArea {
+    /// Creates new [`TextArea`] with given lines and cursor position
+    pub fn new(
+        lines: Rc<RefCell<Vec<String>>>,
+        cursor: Rc<Cell<Vec2>>,
+    ) -> Self {
+        Self {
+            lines,
+            cursor,
+            offset: Cell::new(Vec2::new(0, 0)),
+            tab_width: 4,
+            style: Default::default(),
+            cursor_style: Style::new().modifier(Modifier::INVERSED),
+            term_cursor: false,
+        }
+    }
+
+    /// Sets number of spaces the tab is expanded to
+    pub fn tab_width(mut self, width: usize) -> Self {
+        self.tab_width = width;
+        self
+    }
+
+    /// Sets style of the [`TextArea`] text
+    pub fn style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets style of the cell with cursor (reversed by default)
+    pub fn cursor_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.cursor_style = style.into();
+        self
+    }
+
+    /// Sets whether the terminal cursor is moved to the cursor position when
+    /// rendering (see [`Buffer::set_cursor`]), which is useful for IME
+    pub fn term_cursor(mut self, show: bool) -> Self {
+        self.term_cursor = show;
+        self
+    }
+
+    /// Inserts given character on the cursor position and moves the cursor
+    /// after it
+    pub fn insert_char(&self, c: char) {
+        let mut lines = self.lines.borrow_mut();
+        let cursor = Self::clamp(&mut lines, self.cursor.get());
+
+        let line = &mut lines[cursor.y];
+        let id = Self::byte_id(line, cursor.x);
+        line.insert(id, c);
+        self.cursor.set(Vec2::new(cursor.x + 1, cursor.y));
+    }
+
+    /// Splits the line on the cursor position, moving the cursor to the
+    /// start of the new line
+    pub fn insert_newline(&self) {
+        let mut lines = self.lines.borrow_mut();
+        let cursor = Self::clamp(&mut lines, self.cursor.get());
+
+        let id = Self::byte_id(&lines[cursor.y], cursor.x);
+        let rest = lines[cursor.y].split_off(id);
+        lines.insert(cursor.y + 1, rest);
+        self.cursor.set(Vec2::new(0, cursor.y + 1));
+    }
+
+    /// Removes character before the cursor, joining the line with the
+    /// previous one when cursor is at the start of the line
+    pub fn backspace(&self) {
+        let mut lines = self.lines.borrow_mut();
+        let cursor = Self::clamp(&mut lines, self.cursor.get());
+
+        if cursor.x > 0 {
+            let line = &mut lines[cursor.y];
+            let id = Self::byte_id(line, cursor.x - 1);
+            line.remove(id);
+            self.cursor.set(Vec2::new(cursor.x - 1, cursor.y));
+        } else if cursor.y > 0 {
+            let line = lines.remove(cursor.y);
+            let prev = &mut lines[cursor.y - 1];
+            let x = prev.chars().count();
+            prev.push_str(&line);
+            self.cursor.set(Vec2::new(x, cursor.y - 1));
+        }
+    }
+
+    /// Removes character on the cursor position, joining the line with the
+    /// next one when cursor is at the end of the line
+    pub fn delete(&self) {
+        let mut lines = self.lines.borrow_mut();
+        let cursor = Self::clamp(&mut lines, self.cursor.get());
+
+        if cursor.x < lines[cursor.y].chars().count() {
+            let line = &mut lines[cursor.y];
+            let id = Self::byte_id(line, cursor.x);
+            line.remove(id);
+        } else if cursor.y + 1 < lines.len() {
+            let next = lines.remove(cursor.y + 1);
+            lines[cursor.y].push_str(&next);
+        }
+        self.cursor.set(cursor);
+    }
+
+    /// Moves cursor one character to the left, or to the end of the previous
+    /// line
+    pub fn move_left(&self) {
+        let mut lines = self.lines.borrow_mut();
+        let cursor = Self::clamp(&mut lines, self.cursor.get());
+        if cursor.x > 0 {
+            self.cursor.set(Vec2::new(cursor.x - 1, cursor.y));
+        } else if cursor.y > 0 {
+            let x = lines[cursor.y - 1].chars().count();
+            self.cursor.set(Vec2::new(x, cursor.y - 1));
+        }
+    }
+
+    /// Moves cursor one character to the right, or to the start of the next
+    /// line
+    pub fn move_right(&self) {
+        let mut lines = self.lines.borrow_mut();
+        let cursor = Self::clamp(&mut lines, self.cursor.get());
+        if cursor.x < lines[cursor.y].chars().count() {
+            self.cursor.set(Vec2::new(cursor.x + 1, cursor.y));
+        } else if cursor.y + 1 < lines.len() {
+            self.cursor.set(Vec2::new(0, cursor.y + 1));
+        }
+    }
+
+    /// Moves cursor to the previous line
+    pub fn move_up(&self) {
+        let cursor = self.cursor.get();
+        self.move_to(Vec2::new(cursor.x, cursor.y.saturating_sub(1)));
+    }
+
+    /// Moves cursor to the next line
+    pub fn move_down(&self) {
+        let cursor = self.cursor.get();
+        self.move_to(Vec2::new(cursor.x, cursor.y + 1));
+    }
+
+    /// Moves cursor to the start of the line
+    pub fn home(&self) {
+        self.cursor.set(Vec2::new(0, self.cursor.get().y));
+    }
+
+    /// Moves cursor to the end of the line
+    pub fn end(&self) {
+        self.move_to(Vec2::new(usize::MAX, self.cursor.get().y));
+    }
+}
+
+impl Widget for TextArea {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let lines = self.lines.borrow();
+        let cursor = self.cursor.get();
+        let offset = self.auto_scroll(&lines, cursor, buffer.size());
+
+        let end = buffer.x() + buffer.width();
+        let rows = lines.iter().enumerate().skip(offset.y);
+        for (y, (id, line)) in rows.take(buffer.height()).enumerate() {
+            let mut pos = Vec2::new(buffer.x(), buffer.y() + y);
+            let mut col = 0;
+            for (i, c) in line.chars().enumerate() {
+                let width = self.char_width(c);
+                col += width;
+                if col <= offset.x {
+                    continue;
+                }
+                if pos.x + width > end {
+                    break;
+                }
+
+                let style = if cursor == Vec2::new(i, id) {
+                    self.set_term_cursor(buffer, pos);
+                    self.cursor_style
+                } else {
+                    self.style
+                };
+                if c == '\t' {
+                    let spaces = " ".repeat(width);
+                    buffer.set_str_styled(spaces, &pos, style);
+                } else {
+                    buffer.set_str_styled(c.to_string(), &pos, style);
+                }
+                pos.x += width;
+            }
+
+            let len = line.chars().count();
+            if cursor.y == id && cursor.x >= len && pos.x < end {
+                buffer.set_val(' ', &pos);
+                buffer.set_style(self.cursor_style, &pos);
+                self.set_term_cursor(buffer, pos);
+            }
+        }
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        self.lines.borrow().len()
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        let lines = self.lines.borrow();
+        lines.iter().map(|l| self.line_width(l)).max().unwrap_or(0)
+    }
+}
+
+impl TextArea {
+    /// Gets byte index of the character with given index
+    fn byte_id(text: &str, id: usize) -> usize {
+        text.char_indices().nth(id).map_or(text.len(), |(i, _)| i)
+    }
+
+    /// Clamps cursor to the text, making sure there is at least one line
+    fn clamp(lines: &mut Vec<String>, cursor: Vec2) -> Vec2 {
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        let y = cursor.y.min(lines.len() - 1);
+        Vec2::new(cursor.x.min(lines[y].chars().count()), y)
+    }
+
+    /// Sets terminal cursor to the given position when enabled
+    fn set_term_cursor(&self, buffer: &mut Buffer, pos: Vec2) {
+        if self.term_cursor {
+            buffer.set_cursor(pos);
+        }
+    }
+
+    /// Moves the cursor to the given position, clamped to the text
+    fn move_to(&self, cursor: Vec2) {
+        let mut lines = self.lines.borrow_mut();
+        self.cursor.set(Self::clamp(&mut lines, cursor));
+    }
+
+    /// Gets width of the character, tab is expanded to spaces
+    fn char_width(&self, c: char) -> usize {
+        match c {
+            '\t' => self.tab_width,
+            _ => char_width(c),
+        }
+    }
+
+    /// Gets width of the line with tabs expanded
+    fn line_width(&self, line: &str) -> usize {
+        line.chars().map(|c| self.char_width(c)).sum()
+    }
+
+    /// Adjusts the scroll offset so the cursor is visible and returns it
+    fn auto_scroll(
+        &self,
+        lines: &[String],
+        cursor: Vec2,
+        size: &Vec2,
+    ) -> Vec2 {
+        let mut offset = self.offset.get();
+        if cursor.y < offset.y {
+            offset.y = cursor.y;
+        } else if cursor.y >= offset.y + size.y {
+            offset.y = cursor.y + 1 - size.y;
+        }
+
+        let line = lines.get(cursor.y).map_or("", |l| l.as_str());
+        let mut chars = line.chars().skip(cursor.x);
+        let start =
+            self.line_width(&line.chars().take(cursor.x).collect::<String>());
+        let width = chars.next().map_or(1, |c| self.char_width(c));
+        if start < offset.x {
+            offset.x = start;
+        } else if start + width > offset.x + size.x {
+            offset.x = (start + width).saturating_sub(size.x);
+        }
+
+        self.offset.set(offset);
+        offset
+    }
+}
+
+// From implementations
+impl From<TextArea> for Box<dyn Widget> {
+    fn from(value: TextArea) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<TextArea> for Element {
+    fn from(value: TextArea) -> Self {
+        Element::new(value)
+    }
+}
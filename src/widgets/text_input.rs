@@ -0,0 +1,225 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use crate::{
+    buffer::Buffer,
+    enums::Modifier,
+    geometry::Vec2,
+    style::Style,
+    text::{char_width, str_width},
+};
+
+use super::{widget::Widget, Element};
+
+/// Single-line text input widget with cursor
+///
+/// Both the text and the cursor position (index of the character) are
+/// shared, so they can be edited from the event handler using the
+/// [`TextInput`] functions (such as `insert`) or directly. When the text
+/// doesn't fit, it is scrolled horizontally so the cursor stays visible.
+///
+/// ## Example usage:
+/// ```rust
+/// # use std::{cell::{Cell, RefCell}, rc::Rc};
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     geometry::Rect,
+/// #     widgets::{TextInput, Widget},
+/// # };
+/// let text = Rc::new(RefCell::new(String::from("Hello")));
+/// let cursor = Rc::new(Cell::new(5));
+/// let input = TextInput::new(text.clone(), cursor.clone());
+///
+/// // Edits the text, for example on key press
+/// input.insert('!');
+/// input.home();
+/// assert_eq!(text.borrow().as_str(), "Hello!");
+/// assert_eq!(cursor.get(), 0);
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 10, 1));
+/// input.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct TextInput {
+    text: Rc<RefCell<String>>,
+    cursor: Rc<Cell<usize>>,
+    offset: Cell<usize>,
+    style: Style,
+    cursor_style: Style,
+    term_cursor: bool,
+}
+
+impl TextInput {
+    /// Creates new [`TextInput`] with given text and cursor position
+    pub fn new(text: Rc<RefCell<String>>, cursor: Rc<Cell<usize>>) -> Self {
+        Self {
+            text,
+            cursor,
+            offset: Cell::new(0),
+            style: Default::default(),
+            cursor_style: Style::new().modifier(Modifier::INVERSED),
+            term_cursor: false,
+        }
+    }
+
+    /// Sets style of the [`TextInput`] text
+    pub fn style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets style of the cell with cursor (reversed by default)
+    pub fn cursor_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.cursor_style = style.into();
+        self
+    }
+
+    /// Sets whether the terminal cursor is moved to the cursor position when
+    /// rendering (see [`Buffer::set_cursor`]), which is useful for IME
+    pub fn term_cursor(mut self, show: bool) -> Self {
+        self.term_cursor = show;
+        self
+    }
+
+    /// Inserts given character on the cursor position and moves the cursor
+    /// after it
+    pub fn insert(&self, c: char) {
+        let mut text = self.text.borrow_mut();
+        let cursor = self.cursor.get().min(text.chars().count());
+        let id = Self::byte_id(&text, cursor);
+        text.insert(id, c);
+        self.cursor.set(cursor + 1);
+    }
+
+    /// Removes character before the cursor
+    pub fn backspace(&self) {
+        let mut text = self.text.borrow_mut();
+        let cursor = self.cursor.get().min(text.chars().count());
+        if cursor == 0 {
+            return;
+        }
+        let id = Self::byte_id(&text, cursor - 1);
+        text.remove(id);
+        self.cursor.set(cursor - 1);
+    }
+
+    /// Moves cursor one character to the left
+    pub fn move_left(&self) {
+        self.cursor.set(self.cursor.get().saturating_sub(1));
+    }
+
+    /// Moves cursor one character to the right
+    pub fn move_right(&self) {
+        let len = self.text.borrow().chars().count();
+        self.cursor.set((self.cursor.get() + 1).min(len));
+    }
+
+    /// Moves cursor to the start of the text
+    pub fn home(&self) {
+        self.cursor.set(0);
+    }
+
+    /// Moves cursor to the end of the text
+    pub fn end(&self) {
+        self.cursor.set(self.text.borrow().chars().count());
+    }
+}
+
+impl Widget for TextInput {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let text = self.text.borrow();
+        let chars: Vec<char> = text.chars().collect();
+        let cursor = self.cursor.get().min(chars.len());
+        let offset = self.scroll(&chars, cursor, buffer.width());
+
+        let mut pos = *buffer.pos();
+        let end = buffer.x() + buffer.width();
+        for (i, c) in chars.iter().enumerate().skip(offset) {
+            let width = char_width(*c);
+            if pos.x + width > end {
+                break;
+            }
+
+            let style = if i == cursor {
+                self.set_term_cursor(buffer, pos);
+                self.cursor_style
+            } else {
+                self.style
+            };
+            buffer.set_str_styled(c.to_string(), &pos, style);
+            pos.x += width;
+        }
+
+        if cursor == chars.len() && pos.x < end {
+            buffer.set_val(' ', &pos);
+            buffer.set_style(self.cursor_style, &pos);
+            self.set_term_cursor(buffer, pos);
+        }
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        1
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        str_width(&self.text.borrow())
+    }
+}
+
+impl TextInput {
+    /// Gets byte index of the character with given index
+    fn byte_id(text: &str, id: usize) -> usize {
+        text.char_indices().nth(id).map_or(text.len(), |(i, _)| i)
+    }
+
+    /// Sets terminal cursor to the given position when enabled
+    fn set_term_cursor(&self, buffer: &mut Buffer, pos: Vec2) {
+        if self.term_cursor {
+            buffer.set_cursor(pos);
+        }
+    }
+
+    /// Adjusts the scroll offset so the cursor is visible and returns it
+    fn scroll(&self, chars: &[char], cursor: usize, width: usize) -> usize {
+        let mut offset = self.offset.get().min(cursor);
+        let cursor_width = chars.get(cursor).map_or(1, |c| char_width(*c));
+        let visible = |offset: usize| {
+            chars[offset..cursor]
+                .iter()
+                .map(|c| char_width(*c))
+                .sum::<usize>()
+                + cursor_width
+        };
+        while offset < cursor && visible(offset) > width {
+            offset += 1;
+        }
+        self.offset.set(offset);
+        offset
+    }
+}
+
+// From implementations
+impl From<TextInput> for Box<dyn Widget> {
+    fn from(value: TextInput) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<TextInput> for Element {
+    fn from(value: TextInput) -> Self {
+        Element::new(value)
+    }
+}
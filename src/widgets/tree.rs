@@ -0,0 +1,370 @@
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use crate::{
+    buffer::Buffer,
+    geometry::{Rect, Vec2},
+    style::Style,
+    text::str_width,
+};
+
+use super::{widget::Widget, Element};
+
+/// Tree widget displaying hierarchy of collapsible nodes
+///
+/// Nodes are identified by their index in the pre-order traversal of the
+/// whole tree (including the collapsed nodes), so the first root has id 0,
+/// its first child has id 1 and so on. The [`TreeState`] contains the
+/// expanded nodes, the selected node and the scroll offset, and it's shared,
+/// so it can be changed using the [`Tree`] functions from the event handler.
+///
+/// ## Example usage:
+/// ```rust
+/// # use std::{cell::RefCell, rc::Rc};
+/// # use termint::{
+/// #     buffer::Buffer,
+/// #     enums::Color,
+/// #     geometry::Rect,
+/// #     widgets::{Tree, TreeNode, TreeState, Widget},
+/// # };
+/// let nodes = vec![
+///     TreeNode::new("src")
+///         .child(TreeNode::new("widgets").child(TreeNode::new("tree.rs")))
+///         .child(TreeNode::new("main.rs")),
+///     TreeNode::new("tests"),
+/// ];
+/// let state = Rc::new(RefCell::new(TreeState::new()));
+/// let tree = Tree::new(nodes, state.clone())
+///     .selected_style(Color::Yellow)
+///     .auto_scroll();
+///
+/// // Expands the `src` node and selects the next visible node
+/// tree.toggle(0);
+/// tree.select_next();
+///
+/// let mut buffer = Buffer::empty(Rect::new(1, 1, 20, 5));
+/// tree.render(&mut buffer);
+/// buffer.render();
+/// ```
+#[derive(Debug)]
+pub struct Tree {
+    nodes: Vec<TreeNode>,
+    state: Rc<RefCell<TreeState>>,
+    auto_scroll: bool,
+    style: Style,
+    sel_style: Style,
+}
+
+/// Node of the [`Tree`] containing its label and children
+#[derive(Debug, Clone)]
+pub struct TreeNode {
+    pub label: String,
+    pub children: Vec<TreeNode>,
+}
+
+/// State of the [`Tree`] widget
+#[derive(Debug, Default)]
+pub struct TreeState {
+    pub offset: usize,
+    pub selected: Option<usize>,
+    pub expanded: HashSet<usize>,
+}
+
+/// Visible row of the [`Tree`]
+struct TreeRow<'a> {
+    id: usize,
+    prefix: String,
+    node: &'a TreeNode,
+}
+
+impl Tree {
+    /// Creates new [`Tree`] with given root nodes and given state
+    pub fn new(nodes: Vec<TreeNode>, state: Rc<RefCell<TreeState>>) -> Self {
+        Self {
+            nodes,
+            state,
+            auto_scroll: false,
+            style: Default::default(),
+            sel_style: Default::default(),
+        }
+    }
+
+    /// Automatically scrolls so the selected node is visible
+    pub fn auto_scroll(mut self) -> Self {
+        self.auto_scroll = true;
+        self
+    }
+
+    /// Sets style of the [`Tree`]
+    pub fn style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets style of the selected node in the [`Tree`]
+    pub fn selected_style<T>(mut self, style: T) -> Self
+    where
+        T: Into<Style>,
+    {
+        self.sel_style = style.into();
+        self
+    }
+
+    /// Expands the node with given id when it's collapsed and collapses it
+    /// otherwise
+    pub fn toggle(&self, id: usize) {
+        let mut state = self.state.borrow_mut();
+        if !state.expanded.remove(&id) {
+            state.expanded.insert(id);
+        }
+    }
+
+    /// Expands all the nodes
+    pub fn expand_all(&self) {
+        let count = Self::count(&self.nodes);
+        self.state.borrow_mut().expanded = (0..count).collect();
+    }
+
+    /// Collapses all the nodes. When the selected node gets hidden, its root
+    /// is selected
+    pub fn collapse_all(&self) {
+        let mut state = self.state.borrow_mut();
+        state.expanded.clear();
+        if let Some(selected) = state.selected {
+            let mut root = 0;
+            for node in self.nodes.iter() {
+                let next = root + 1 + Self::count(&node.children);
+                if selected < next {
+                    break;
+                }
+                root = next;
+            }
+            state.selected = Some(root);
+        }
+    }
+
+    /// Selects the next visible node, skipping the collapsed subtrees
+    pub fn select_next(&self) {
+        let rows = self.rows();
+        let mut state = self.state.borrow_mut();
+        let next = match state.selected {
+            Some(id) => rows
+                .iter()
+                .position(|r| r.id == id)
+                .map_or(0, |p| (p + 1).min(rows.len().saturating_sub(1))),
+            None => 0,
+        };
+        state.selected = rows.get(next).map(|r| r.id);
+    }
+
+    /// Selects the previous visible node, skipping the collapsed subtrees
+    pub fn select_prev(&self) {
+        let rows = self.rows();
+        let mut state = self.state.borrow_mut();
+        let prev = match state.selected {
+            Some(id) => rows
+                .iter()
+                .position(|r| r.id == id)
+                .map_or(0, |p| p.saturating_sub(1)),
+            None => 0,
+        };
+        state.selected = rows.get(prev).map(|r| r.id);
+    }
+
+    /// Gets the selected node
+    pub fn selected_node(&self) -> Option<&TreeNode> {
+        let selected = self.state.borrow().selected?;
+        Self::find(&self.nodes, selected, &mut 0)
+    }
+}
+
+impl TreeNode {
+    /// Creates new [`TreeNode`] with given label and no children
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            label: label.into(),
+            children: vec![],
+        }
+    }
+
+    /// Adds given child to the [`TreeNode`]
+    pub fn child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+impl TreeState {
+    /// Creates new [`TreeState`] with no nodes expanded and no node selected
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates new [`TreeState`] with given node selected
+    pub fn selected(selected: usize) -> Self {
+        Self {
+            selected: Some(selected),
+            ..Default::default()
+        }
+    }
+}
+
+impl Widget for Tree {
+    fn render(&self, buffer: &mut Buffer) {
+        if buffer.area() == 0 {
+            return;
+        }
+
+        let rows = self.rows();
+        if self.auto_scroll {
+            self.scroll_offset(&rows, buffer.height());
+        }
+
+        let state = self.state.borrow();
+        let visible = rows.iter().skip(state.offset).take(buffer.height());
+        for (y, row) in visible.enumerate() {
+            let style = if state.selected == Some(row.id) {
+                self.sel_style
+            } else {
+                self.style
+            };
+
+            let rect =
+                Rect::new(buffer.x(), buffer.y() + y, buffer.width(), 1);
+            let mut rbuffer = buffer.subset(rect);
+            let text = format!("{}{}", row.prefix, row.node.label);
+            rbuffer.set_str_styled(text, rect.pos(), style);
+            buffer.merge(rbuffer);
+        }
+    }
+
+    fn height(&self, _size: &Vec2) -> usize {
+        self.rows().len()
+    }
+
+    fn width(&self, _size: &Vec2) -> usize {
+        self.rows()
+            .iter()
+            .map(|r| str_width(&r.prefix) + str_width(&r.node.label))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Tree {
+    /// Gets visible rows of the [`Tree`]
+    fn rows(&self) -> Vec<TreeRow<'_>> {
+        let state = self.state.borrow();
+        let mut rows = Vec::new();
+        let mut id = 0;
+        for node in self.nodes.iter() {
+            Self::push_rows(&mut rows, node, &mut id, &state.expanded, None);
+        }
+        rows
+    }
+
+    /// Pushes rows of the given node and its expanded children. `guides`
+    /// contains the guides of the parent rows and whether the node is the
+    /// last child, it's `None` for the root nodes
+    fn push_rows<'a>(
+        rows: &mut Vec<TreeRow<'a>>,
+        node: &'a TreeNode,
+        id: &mut usize,
+        expanded: &HashSet<usize>,
+        guides: Option<(&str, bool)>,
+    ) {
+        let node_id = *id;
+        *id += 1;
+
+        let is_expanded = expanded.contains(&node_id);
+        let (prefix, child_guides) = match guides {
+            Some((guides, last)) => {
+                let (connector, next) = if last {
+                    ("└─ ", "   ")
+                } else {
+                    ("├─ ", "│  ")
+                };
+                (format!("{guides}{connector}"), format!("{guides}{next}"))
+            }
+            None => (String::new(), String::new()),
+        };
+        let indicator = match (node.children.is_empty(), is_expanded) {
+            (true, _) => "",
+            (false, true) => "▾ ",
+            (false, false) => "▸ ",
+        };
+        rows.push(TreeRow {
+            id: node_id,
+            prefix: format!("{prefix}{indicator}"),
+            node,
+        });
+
+        if !is_expanded {
+            *id += Self::count(&node.children);
+            return;
+        }
+        for (i, child) in node.children.iter().enumerate() {
+            let last = i + 1 == node.children.len();
+            let guides = Some((child_guides.as_str(), last));
+            Self::push_rows(rows, child, id, expanded, guides);
+        }
+    }
+
+    /// Gets total number of the given nodes and their descendants
+    fn count(nodes: &[TreeNode]) -> usize {
+        nodes.iter().map(|n| 1 + Self::count(&n.children)).sum()
+    }
+
+    /// Finds node with given id, `id` is the id of the first given node
+    fn find<'a>(
+        nodes: &'a [TreeNode],
+        target: usize,
+        id: &mut usize,
+    ) -> Option<&'a TreeNode> {
+        for node in nodes {
+            if *id == target {
+                return Some(node);
+            }
+            *id += 1;
+            if let Some(node) = Self::find(&node.children, target, id) {
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    /// Automatically scrolls so the selected node is visible
+    fn scroll_offset(&self, rows: &[TreeRow], height: usize) {
+        let mut state = self.state.borrow_mut();
+        let Some(selected) = state.selected else {
+            return;
+        };
+        let Some(pos) = rows.iter().position(|r| r.id == selected) else {
+            return;
+        };
+
+        if pos < state.offset {
+            state.offset = pos;
+        } else if pos >= state.offset + height {
+            state.offset = pos + 1 - height;
+        }
+    }
+}
+
+// From implementations
+impl From<Tree> for Box<dyn Widget> {
+    fn from(value: Tree) -> Self {
+        Box::new(value)
+    }
+}
+
+impl From<Tree> for Element {
+    fn from(value: Tree) -> Self {
+        Element::new(value)
+    }
+}
@@ -1,9 +1,9 @@
-use std::fmt;
+use std::{any::Any, fmt};
 
 use crate::{buffer::Buffer, geometry::Vec2};
 
 /// Trait for widgets to implement
-pub trait Widget {
+pub trait Widget: Any {
     /// Renders [`Widget`] on given position with given size
     fn render(&self, buffer: &mut Buffer);
 
@@ -12,6 +12,35 @@ pub trait Widget {
 
     /// Gets width of the [`Widget`]
     fn width(&self, size: &Vec2) -> usize;
+
+    /// Gets children of the [`Widget`], empty when it has no children
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![]
+    }
+}
+
+impl dyn Widget {
+    /// Gets reference to the widget as type `W`, `None` when the widget isn't
+    /// of the type `W`
+    pub fn downcast_ref<W: Widget>(&self) -> Option<&W> {
+        (self as &dyn Any).downcast_ref::<W>()
+    }
+
+    /// Finds first widget of type `W` in the widget tree using depth-first
+    /// search (the widget itself is checked first)
+    pub fn find<W: Widget>(&self) -> Option<&W> {
+        self.downcast_ref()
+            .or_else(|| self.children().into_iter().find_map(|c| c.find()))
+    }
+
+    /// Finds all widgets of type `W` in the widget tree, in depth-first order
+    pub fn find_all<W: Widget>(&self) -> Vec<&W> {
+        let mut res: Vec<&W> = self.downcast_ref().into_iter().collect();
+        for child in self.children() {
+            res.extend(child.find_all::<W>());
+        }
+        res
+    }
 }
 
 impl fmt::Debug for dyn Widget {
@@ -31,6 +60,32 @@ impl Element {
     {
         Element(Box::new(widget))
     }
+
+    /// Gets reference to the wrapped widget
+    pub fn as_widget(&self) -> &dyn Widget {
+        self.0.as_ref()
+    }
+
+    /// Gets reference to the wrapped widget as type `W`, `None` when the
+    /// wrapped widget isn't of the type `W`
+    pub fn downcast_ref<W: Widget>(&self) -> Option<&W> {
+        self.0.downcast_ref()
+    }
+
+    /// Finds first widget of type `W` in the element tree using depth-first
+    /// search (the wrapped widget is checked first)
+    ///
+    /// This is mostly useful for testing and tooling, when you need to get
+    /// to a deeply nested widget.
+    pub fn find<W: Widget>(&self) -> Option<&W> {
+        self.0.find()
+    }
+
+    /// Finds all widgets of type `W` in the element tree, in depth-first
+    /// order
+    pub fn find_all<W: Widget>(&self) -> Vec<&W> {
+        self.0.find_all()
+    }
 }
 
 impl Widget for Element {
@@ -45,4 +100,14 @@ impl Widget for Element {
     fn width(&self, size: &Vec2) -> usize {
         self.0.width(size)
     }
+
+    fn children(&self) -> Vec<&dyn Widget> {
+        vec![self.as_widget()]
+    }
+}
+
+impl From<Box<dyn Widget>> for Element {
+    fn from(value: Box<dyn Widget>) -> Self {
+        Element(value)
+    }
 }
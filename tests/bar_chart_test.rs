@@ -0,0 +1,47 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, Vec2},
+        widgets::{BarChart, Widget},
+    };
+
+    fn row(buffer: &Buffer, y: usize) -> String {
+        (0..buffer.width())
+            .map(|x| buffer.cell(&Vec2::new(x, y)).unwrap().val)
+            .collect()
+    }
+
+    /// Tests bar chart size
+    #[test]
+    fn bar_chart_size() {
+        let chart = BarChart::new(vec![("a".into(), 1), ("b".into(), 2)])
+            .chart_height(5)
+            .bar_width(3)
+            .gap(2);
+        let size = Vec2::new(20, 20);
+        assert_eq!(chart.width(&size), 8);
+        assert_eq!(chart.height(&size), 5);
+    }
+
+    /// Tests rendering bars with partial top cells, values and labels
+    #[test]
+    fn bar_chart_render() {
+        let chart = BarChart::new(vec![
+            ("a".into(), 16),
+            ("bb".into(), 6),
+            ("c".into(), 0),
+        ])
+        .bar_width(2)
+        .show_values(true);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+        chart.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "██      ");
+        assert_eq!(row(&buffer, 1), "██ ▆▆   ");
+        assert_eq!(row(&buffer, 2), "16 6  0 ");
+        assert_eq!(row(&buffer, 3), "a  bb c ");
+    }
+}
@@ -0,0 +1,44 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::{Buffer, Cell},
+        enums::Color,
+        geometry::Rect,
+        widgets::{BgGrad, Empty, Widget},
+    };
+
+    /// Tests that blended gradient mixes with the existing background and
+    /// keeps the existing content
+    #[test]
+    fn bg_grad_blend() {
+        let cell = Cell::new('x').fg(Color::Red).bg(Color::Rgb(0, 0, 100));
+        let mut buffer = Buffer::filled(Rect::new(0, 0, 2, 1), cell);
+
+        let grad = BgGrad::horizontal(Empty, (200, 200, 200), (200, 200, 200))
+            .blend(0.5);
+        grad.render(&mut buffer);
+
+        for cell in buffer.content() {
+            assert_eq!(cell.val, 'x');
+            assert_eq!(cell.fg, Color::Red);
+            assert_eq!(cell.bg, Color::Rgb(100, 100, 150));
+        }
+    }
+
+    /// Tests that diagonal gradient depends on sum of the coordinates
+    #[test]
+    fn bg_grad_diagonal() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        let grad = BgGrad::diagonal(Empty, (0, 0, 0), (150, 0, 0));
+        grad.render(&mut buffer);
+
+        for (pos, r) in
+            [((0, 0), 0), ((1, 0), 50), ((0, 1), 50), ((2, 1), 150)]
+        {
+            assert_eq!(buffer[pos].bg, Color::Rgb(r, 0, 0));
+        }
+        assert_eq!(buffer[(2, 0)].bg, buffer[(1, 1)].bg);
+    }
+}
@@ -4,8 +4,9 @@ extern crate termint;
 mod tests {
     use termint::{
         buffer::Buffer,
-        geometry::{Vec2, Rect},
-        widgets::{Block, StrSpanExtension, Widget},
+        geometry::{Rect, TextAlign, Vec2},
+        text::Text,
+        widgets::{Block, BorderType, StrSpanExtension, Widget},
     };
 
     #[test]
@@ -17,4 +18,105 @@ mod tests {
         ));
         block.render(&mut buffer);
     }
+
+    fn row(buffer: &Buffer, y: usize) -> String {
+        (buffer.x()..buffer.x() + buffer.width())
+            .map(|x| buffer.cell(&Vec2::new(x, y)).unwrap().val)
+            .collect()
+    }
+
+    /// Tests aligned top title and bottom title
+    #[test]
+    fn block_title_align() {
+        let block = Block::vertical()
+            .title("Top".to_span())
+            .title_align(TextAlign::Center)
+            .bottom_title("Hint".to_span())
+            .bottom_title_align(TextAlign::Right);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        block.render(&mut buffer);
+
+        assert_eq!(row(&buffer, 0), "┌──Top──┐");
+        assert_eq!(row(&buffer, 2), "└───Hint┘");
+    }
+
+    /// Tests truncating title, which keeps the corners intact
+    #[test]
+    fn block_title_truncate() {
+        let block = Block::vertical()
+            .title("Long title".to_span().ellipsis(".."))
+            .title_align(TextAlign::Right);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        block.render(&mut buffer);
+
+        assert_eq!(row(&buffer, 0), "┌Long..┐");
+    }
+
+    /// Tests block with different border type on each side
+    #[test]
+    fn block_border_types() {
+        let block = Block::empty().border_types(
+            BorderType::Thicker,
+            BorderType::Normal,
+            BorderType::Rounded,
+            BorderType::Double,
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        block.render(&mut buffer);
+
+        assert_eq!(row(&buffer, 0), "┏━━┑");
+        assert_eq!(row(&buffer, 1), "║  │");
+        assert_eq!(row(&buffer, 2), "╙──╯");
+    }
+
+    /// Tests left, center and right titles and their clipping
+    #[test]
+    fn block_titles() {
+        let block = Block::vertical().titles(
+            "main.rs".to_span(),
+            "Edit".to_span(),
+            "1:2".to_span(),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 2));
+        block.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "┌main.rs─────Edit─────────1:2┐");
+
+        // Center title is moved between the side titles when it collides
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+        block.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "┌main.rs──Edit──1:2┐");
+
+        // Center title is hidden when there's no space left for it
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 2));
+        block.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "┌main.rs──1:2┐");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 2));
+        block.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "┌main.rs┐");
+
+        assert_eq!(block.width(&Vec2::new(30, 2)), 18);
+    }
+
+    /// Tests that block width uses display width of the titles
+    #[test]
+    fn block_title_display_width() {
+        let title = "Ωμέγα".to_span();
+        assert_eq!(title.display_width(), 5);
+        let block = Block::empty().title(title);
+        assert_eq!(block.width(&Vec2::new(20, 5)), 7);
+
+        let block = Block::empty().bottom_title("中文".to_span());
+        assert_eq!(block.width(&Vec2::new(20, 5)), 6);
+    }
+
+    /// Tests building block with children in a single expression
+    #[test]
+    fn block_child_chain() {
+        let block = Block::vertical().child("a", 1).child("b", 1);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 4));
+        block.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "┌─┐\n│a│\n│b│\n└─┘");
+    }
 }
@@ -2,7 +2,7 @@
 mod tests {
     use termint::{
         borders,
-        widgets::{Border, BorderType},
+        widgets::{Border, BorderSet, BorderType},
     };
 
     /// Test borders macro usage for combining border sides
@@ -32,4 +32,14 @@ mod tests {
         assert_eq!(border_type.get(borders!(BOTTOM, LEFT)), '└');
         assert_eq!(border_type.get(borders!(BOTTOM, RIGHT)), '┘');
     }
+
+    /// Tests custom border type with ASCII characters
+    #[test]
+    fn border_type_custom() {
+        let border_type = BorderType::Custom(BorderSet::ascii());
+        assert_eq!(border_type.get(Border::TOP), '-');
+        assert_eq!(border_type.get(Border::RIGHT), '|');
+        assert_eq!(border_type.get(borders!(BOTTOM, LEFT)), '+');
+        assert_eq!(border_type.get(Border::ALL), '+');
+    }
 }
@@ -0,0 +1,286 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::{Buffer, Cell, Link},
+        enums::{Color, ColorMode},
+        geometry::{Rect, Vec2},
+        text::str_width,
+    };
+
+    /// Tests rendering buffer to a writer and diff rendering only the changes
+    #[test]
+    fn buffer_render_to() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 3, 1));
+        buffer.set_str("abc", &(1, 1).into());
+
+        let mut out = Vec::new();
+        buffer.render_to(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "\x1b[1;1Habc\x1b[0m");
+
+        let mut changed = buffer.clone();
+        changed.set_val('x', &(3, 1).into());
+
+        let mut out = Vec::new();
+        changed.render_diff_to(&mut out, &buffer).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "\x1b[1;3Hx\x1b[0m");
+    }
+
+    /// Tests finding text in the buffer and highlighting it
+    #[test]
+    fn buffer_find_highlight() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 5, 2));
+        buffer.set_str("ab ababc  ", &(1, 1).into());
+
+        let found = buffer.find("ab");
+        assert_eq!(
+            found,
+            vec![Vec2::new(1, 1), Vec2::new(4, 1), Vec2::new(1, 2)]
+        );
+        assert!(buffer.find("x").is_empty());
+
+        buffer.highlight(&found, 2, Color::Red);
+        let red: Vec<bool> = buffer
+            .content()
+            .iter()
+            .map(|c| c.fg == Color::Red)
+            .collect();
+        assert_eq!(
+            red,
+            vec![
+                true, true, false, true, true, true, true, false, false, false
+            ]
+        );
+    }
+
+    /// Tests finding wide characters and grapheme clusters and highlighting
+    /// them by their display width
+    #[test]
+    fn buffer_find_wide() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        buffer.set_str("a中文e\u{301}x", &(0, 0).into());
+
+        let found = buffer.find("中文");
+        assert_eq!(found, vec![Vec2::new(1, 0)]);
+        assert_eq!(buffer.find("e\u{301}x"), vec![Vec2::new(5, 0)]);
+        assert_eq!(buffer.find("文e"), vec![]);
+
+        buffer.highlight(&found, str_width("中文"), Color::Red);
+        let red: Vec<bool> = buffer
+            .content()
+            .iter()
+            .map(|c| c.fg == Color::Red)
+            .collect();
+        assert_eq!(
+            red,
+            vec![false, true, true, true, true, false, false, false]
+        );
+    }
+
+    /// Tests that wide characters take two cells and aren't split between
+    /// rows
+    #[test]
+    fn buffer_wide_chars() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 3, 2));
+        buffer.set_str("a中文", &(1, 1).into());
+
+        let text: String = buffer.content().iter().map(|c| c.val).collect();
+        assert_eq!(text, "a中 文  ");
+
+        let mut out = Vec::new();
+        buffer.render_to(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[1;1Ha中\x1b[2;1H文 \x1b[0m"
+        );
+    }
+
+    /// Tests getting buffer content as ANSI and plain string
+    #[test]
+    fn buffer_to_string() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 4, 2));
+        buffer.set_str("ab中c", &(1, 1).into());
+        buffer.set_fg(Color::Red, &Vec2::new(1, 1));
+
+        assert_eq!(buffer.to_plain_string(), "ab中\nc   ");
+
+        let mut out = Vec::new();
+        buffer.render_to(&mut out).unwrap();
+        assert_eq!(buffer.to_ansi_string(), String::from_utf8(out).unwrap());
+    }
+
+    /// Tests that buffer converts colors based on its color mode
+    #[test]
+    fn buffer_color_mode() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 1, 1));
+        buffer.set_fg(Color::Rgb(255, 0, 0), &(1, 1).into());
+        buffer.set_color_mode(ColorMode::Ansi16);
+
+        let mut out = Vec::new();
+        buffer.render_to(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[1;1H\x1b[91m \x1b[0m"
+        );
+    }
+
+    /// Tests that contiguous cells with the same link are grouped
+    #[test]
+    fn buffer_link_group() {
+        let link = Link::new("https://example.com");
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 4, 1));
+        buffer.set_str("ab", &(1, 1).into());
+        buffer.set_link(Some(link.clone()), &(1, 1).into());
+        buffer.set_link(Some(link.clone()), &(2, 1).into());
+
+        let ansi = buffer.to_ansi_string();
+        assert_eq!(ansi.matches(&link.start()).count(), 1);
+        assert_eq!(ansi.matches(Link::end()).count(), 1);
+        assert!(ansi.contains(&format!("{}ab{}", link.start(), Link::end())));
+    }
+
+    /// Tests getting changed cells compared to the previous buffer
+    #[test]
+    fn buffer_diff_cells() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 3, 2));
+        buffer.set_str("abcdef", &(1, 1).into());
+        assert!(buffer.diff_cells(&buffer.clone()).is_empty());
+
+        let mut changed = buffer.clone();
+        changed.set_val('x', &(2, 1).into());
+        changed.set_fg(Color::Red, &(3, 2).into());
+        let diff = changed.diff_cells(&buffer);
+        assert_eq!(
+            diff,
+            vec![
+                (Vec2::new(2, 1), Cell::new('x')),
+                (Vec2::new(3, 2), Cell::new('f').fg(Color::Red)),
+            ]
+        );
+
+        let resized = Buffer::empty(Rect::new(1, 1, 2, 2));
+        assert_eq!(resized.diff_cells(&buffer).len(), 4);
+    }
+
+    /// Tests filling rect only touches the cells inside of it
+    #[test]
+    fn buffer_fill_rect() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 4, 3));
+        buffer.fill_rect(Rect::new(2, 2, 2, 1), Cell::new('#').fg(Color::Red));
+        assert_eq!(buffer.to_plain_string(), "    \n ## \n    ");
+        assert_eq!(buffer[(2, 2)].fg, Color::Red);
+        assert_eq!(buffer[(1, 2)].fg, Color::Default);
+
+        buffer.fill_style(Rect::new(1, 1, 1, 3), Color::Blue);
+        assert_eq!(buffer[(1, 3)].fg, Color::Blue);
+        assert_eq!(buffer[(2, 3)].fg, Color::Default);
+        assert_eq!(buffer.to_plain_string(), "    \n ## \n    ");
+    }
+
+    /// Tests that filling out of bounds rect is clipped to the buffer
+    #[test]
+    fn buffer_fill_clip() {
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 3, 3));
+        buffer.fill_rect(Rect::new(0, 0, 3, 2), Cell::new('#'));
+        assert_eq!(buffer.to_plain_string(), "## \n   \n   ");
+
+        buffer.fill_rect(Rect::new(10, 10, 3, 3), Cell::new('x'));
+        buffer.fill_rect(Rect::new(0, 0, 0, 5), Cell::new('x'));
+        assert_eq!(buffer.to_plain_string(), "## \n   \n   ");
+
+        buffer.draw_hline(&Vec2::new(2, 3), 10, '-', Color::Green);
+        buffer.draw_vline(&Vec2::new(3, 0), 10, '|', Color::Green);
+        assert_eq!(buffer.to_plain_string(), "##|\n  |\n -|");
+        assert_eq!(buffer[(2, 3)].fg, Color::Green);
+    }
+
+    /// Tests that filling rect disjoint from the buffer or touching only its
+    /// edge doesn't change any cell
+    #[test]
+    fn buffer_fill_out_of_bounds() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        buffer.fill_rect(Rect::new(7, 0, 3, 3), Cell::new('X'));
+        buffer.fill_rect(Rect::new(5, 0, 2, 2), Cell::new('X'));
+        buffer.fill_rect(Rect::new(0, 3, 2, 2), Cell::new('X'));
+        buffer.fill_style(Rect::new(6, 1, 2, 2), Color::Red);
+        buffer.draw_vline(&Vec2::new(9, 0), 3, '|', Color::Green);
+        buffer.draw_hline(&Vec2::new(0, 4), 3, '-', Color::Green);
+        assert_eq!(buffer.to_plain_string(), "     \n     \n     ");
+        assert!(buffer
+            .rect()
+            .into_iter()
+            .all(|p| buffer[p].fg == Color::Default));
+    }
+
+    /// Tests that try setters do nothing outside of the buffer
+    #[test]
+    fn buffer_try_set() {
+        let mut buffer = Buffer::empty(Rect::new(2, 2, 3, 2));
+        assert!(buffer.try_set_val('a', &Vec2::new(2, 2)));
+        assert!(!buffer.try_set_val('b', &Vec2::new(1, 2)));
+        assert!(!buffer.try_set_val('b', &Vec2::new(2, 4)));
+
+        assert!(buffer.try_set_style(Color::Red, &Vec2::new(4, 3)));
+        assert!(!buffer.try_set_style(Color::Red, &Vec2::new(5, 3)));
+        assert_eq!(buffer[(4, 3)].fg, Color::Red);
+
+        assert!(buffer.try_set_str("xyz", &Vec2::new(3, 3)));
+        assert!(!buffer.try_set_str("xyz", &Vec2::new(0, 0)));
+        assert_eq!(buffer.to_plain_string(), "a  \n xy");
+    }
+
+    /// Tests that grapheme clusters are kept in a single cell
+    #[test]
+    fn buffer_grapheme_cluster() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        buffer.set_str("cafe\u{0301}!", &Vec2::new(0, 0));
+        assert_eq!(buffer[(3, 0)].symbol(), "e\u{0301}");
+        assert_eq!(buffer[(4, 0)].val, '!');
+        assert_eq!(buffer.to_plain_string(), "cafe\u{0301}!");
+
+        // Zero width joiner sequence takes the width of the first character
+        let family = "\u{1F468}\u{200D}\u{1F469}";
+        buffer.set_str(format!("{family}xyz"), &Vec2::new(0, 0));
+        assert_eq!(buffer[(0, 0)].symbol(), family);
+        assert_eq!(buffer[(2, 0)].val, 'x');
+        assert_eq!(buffer.to_plain_string(), format!("{family}xyz"));
+
+        // Overwriting the cell replaces the whole cluster
+        buffer.set_val('a', &Vec2::new(0, 0));
+        assert!(buffer[(0, 0)].tail.is_empty());
+
+        let cell = Cell::with_grapheme("o\u{0308}");
+        assert_eq!(cell.val, 'o');
+        assert_eq!(cell.tail.as_str(), "\u{0308}");
+        assert_eq!(Cell::with_grapheme(""), Cell::default());
+    }
+
+    /// Tests emoji modifiers and flags made of regional indicators
+    #[test]
+    fn buffer_grapheme_emoji() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let thumb = "\u{1F44D}\u{1F3FD}";
+        buffer.set_str(format!("{thumb}x"), &Vec2::new(0, 0));
+        assert_eq!(buffer[(0, 0)].symbol(), thumb);
+        assert_eq!(buffer[(0, 0)].width(), 2);
+        assert_eq!(buffer[(2, 0)].val, 'x');
+        assert_eq!(buffer.to_plain_string(), format!("{thumb}x   "));
+
+        // Third regional indicator starts a new flag
+        let flag = "\u{1F1E8}\u{1F1FF}";
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        buffer.set_str(format!("{flag}\u{1F1E6}x"), &Vec2::new(0, 0));
+        assert_eq!(buffer[(0, 0)].symbol(), flag);
+        assert_eq!(buffer[(0, 0)].width(), 2);
+        assert_eq!(buffer[(2, 0)].val, '\u{1F1E6}');
+        assert_eq!(buffer[(3, 0)].val, 'x');
+        assert_eq!(buffer.to_plain_string(), format!("{flag}\u{1F1E6}x  "));
+
+        // Subdivision flag made of tag characters fits into one cell
+        let england =
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}";
+        buffer.set_str(england, &Vec2::new(0, 0));
+        assert_eq!(buffer[(0, 0)].symbol(), england);
+    }
+}
@@ -0,0 +1,87 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, Vec2},
+        widgets::{Calendar, CalendarState, Date, Widget},
+    };
+
+    /// Gets string representation of the given row of the buffer
+    fn row(buffer: &Buffer, y: usize) -> String {
+        (buffer.x()..=buffer.right())
+            .map(|x| buffer[(x, y)].val)
+            .collect()
+    }
+
+    /// Tests date arithmetic used by the calendar
+    #[test]
+    fn calendar_date() {
+        let date = Date::new(2024, 2, 28);
+        assert_eq!(date.add_days(1), Date::new(2024, 2, 29));
+        assert_eq!(date.add_days(2), Date::new(2024, 3, 1));
+        assert_eq!(date.add_days(-59), Date::new(2023, 12, 31));
+        assert_eq!(
+            Date::new(2023, 1, 31).add_months(1),
+            Date::new(2023, 2, 28)
+        );
+        assert_eq!(
+            Date::new(2024, 1, 15).add_months(-1),
+            Date::new(2023, 12, 15)
+        );
+        assert_eq!(Date::new(2026, 10, 16).weekday(), 4);
+        assert_eq!(Date::new(1970, 1, 1).weekday(), 3);
+    }
+
+    /// Tests that invalid month and day are clamped to the valid range
+    #[test]
+    fn calendar_date_clamp() {
+        let date = Date::new(2024, 0, 0);
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 1, 1));
+        assert_eq!(Date::new(2024, 13, 40), Date::new(2024, 12, 31));
+        assert_eq!(Date::new(2023, 2, 30), Date::new(2023, 2, 28));
+    }
+
+    /// Tests rendering the calendar into areas smaller than its grid
+    #[test]
+    fn calendar_render_small() {
+        let state =
+            Rc::new(Cell::new(CalendarState::new(Date::new(2026, 10, 16))));
+        let calendar = Calendar::new(state);
+
+        for (width, height) in [(1, 2), (3, 3), (7, 2), (19, 8)] {
+            let rect = Rect::new(1, 1, width, height);
+            let mut buffer = Buffer::empty(rect);
+            calendar.render(&mut buffer);
+            assert_eq!(*buffer.rect(), rect);
+        }
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 2));
+        calendar.render(&mut buffer);
+        assert_eq!(row(&buffer, 1), "Mo Tu W");
+    }
+
+    /// Tests navigating and rendering the calendar
+    #[test]
+    fn calendar_render() {
+        let state =
+            Rc::new(Cell::new(CalendarState::new(Date::new(2026, 9, 30))));
+        let calendar = Calendar::new(state.clone());
+        calendar.select_next_day();
+        assert_eq!(state.get().selected, Date::new(2026, 10, 1));
+
+        let size = Vec2::new(30, 30);
+        assert_eq!(calendar.width(&size), 20);
+        assert_eq!(calendar.height(&size), 7);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 7));
+        calendar.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "    October 2026    ");
+        assert_eq!(row(&buffer, 1), "Mo Tu We Th Fr Sa Su");
+        assert_eq!(row(&buffer, 2), "          1  2  3  4");
+        assert_eq!(row(&buffer, 6), "26 27 28 29 30 31   ");
+    }
+}
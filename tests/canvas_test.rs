@@ -0,0 +1,40 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        enums::Color,
+        geometry::{Rect, Vec2},
+        widgets::{Canvas, Widget},
+    };
+
+    /// Tests drawing shapes clipped to the canvas area
+    #[test]
+    fn canvas_draw() {
+        let canvas = Canvas::new(|painter| {
+            painter.rect(Rect::new(0, 0, 4, 3), Color::Blue);
+            painter.line(Vec2::new(5, 0), Vec2::new(7, 2), Color::Red);
+            painter.point(Vec2::new(2, 1), Color::Green);
+        });
+
+        let mut buffer = Buffer::empty(Rect::new(1, 1, 7, 3));
+        canvas.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "████ █ \n█ ██  █\n████   ");
+        assert_eq!(buffer[(3, 2)].fg, Color::Green);
+        assert_eq!(buffer[(7, 2)].fg, Color::Red);
+    }
+
+    /// Tests drawing line to a point far outside of the canvas area
+    #[test]
+    fn canvas_line_far() {
+        let canvas = Canvas::new(|painter| {
+            let end = Vec2::new(1_000_000_000_000, 1);
+            painter.line(Vec2::new(0, 1), end, Color::Red);
+        });
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        canvas.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "   \n███");
+    }
+}
@@ -0,0 +1,37 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        geometry::Rect,
+        widgets::{Block, Center, Widget},
+    };
+
+    /// Tests centering widget on both axes and on single axis
+    #[test]
+    fn center_render() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+        Center::new(Block::vertical().title("Hi")).render(&mut buffer);
+        assert_eq!(
+            buffer.to_plain_string(),
+            "        \n  ┌Hi┐  \n  └──┘  \n        "
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        let center = Center::new(Block::vertical().title("Hi")).horizontal();
+        center.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "  ┌Hi┐  \n  │  │  \n  └──┘  ");
+    }
+
+    /// Tests clipping the child bigger than the area
+    #[test]
+    fn center_clip() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Center::new(Block::vertical().title("Hello")).render(&mut buffer);
+        assert_eq!(*buffer.rect(), Rect::new(0, 0, 3, 3));
+        assert_eq!(buffer[(0, 0)].val, '┌');
+        assert_eq!(buffer[(2, 1)].val, '┘');
+        assert_eq!(buffer[(0, 2)].val, ' ');
+    }
+}
@@ -0,0 +1,55 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        enums::Color,
+        geometry::Rect,
+        widgets::{Chart, Widget},
+    };
+
+    /// Tests plotting points into braille dots
+    #[test]
+    fn chart_points() {
+        let chart = Chart::new().x_bounds(0., 3.).y_bounds(0., 7.).series(
+            "a",
+            vec![(0., 7.), (3., 0.), (1., 6.), (5., 5.)],
+            Color::Red,
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 2));
+        chart.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "⠑ \n ⢀");
+        assert_eq!(buffer[(0, 0)].fg, Color::Red);
+        assert_eq!(buffer[(1, 0)].fg, Color::Default);
+    }
+
+    /// Tests connecting points with lines
+    #[test]
+    fn chart_lines() {
+        let chart = Chart::new()
+            .x_bounds(0., 3.)
+            .y_bounds(0., 3.)
+            .series("a", vec![(0., 3.), (3., 3.)], Color::Red)
+            .lines(true);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        chart.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "⠉⠉");
+    }
+
+    /// Tests that lines to points far outside of the bounds are clipped
+    #[test]
+    fn chart_lines_far_point() {
+        for far in [1e13, 1e300] {
+            let chart = Chart::new()
+                .series("a", vec![(0., 1.), (far, 1.)], Color::Red)
+                .lines(true);
+
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+            chart.render(&mut buffer);
+            assert_eq!(buffer.to_plain_string(), "⠉⠉");
+        }
+    }
+}
@@ -0,0 +1,33 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::enums::{Color, ColorMode};
+
+    /// Tests converting colors to the 16 color mode
+    #[test]
+    fn color_to_ansi16() {
+        assert_eq!(Color::Rgb(255, 0, 0).to_ansi16(), Color::Red);
+        assert_eq!(Color::Hex(0x0000c0).to_ansi16(), Color::DarkBlue);
+        assert_eq!(Color::Indexed(2).to_ansi16(), Color::DarkGreen);
+        assert_eq!(Color::Indexed(231).to_ansi16(), Color::White);
+        assert_eq!(Color::Yellow.to_ansi16(), Color::Yellow);
+        assert_eq!(Color::Default.to_ansi16(), Color::Default);
+    }
+
+    /// Tests converting colors to the 256 color mode
+    #[test]
+    fn color_to_ansi256() {
+        assert_eq!(Color::Rgb(255, 0, 0).to_ansi256(), Color::Indexed(196));
+        assert_eq!(Color::Rgb(100, 140, 210).to_ansi256(), Color::Indexed(68));
+        assert_eq!(
+            Color::Rgb(128, 128, 128).to_ansi256(),
+            Color::Indexed(244)
+        );
+        assert_eq!(Color::Indexed(3).to_ansi256(), Color::Indexed(3));
+        assert_eq!(
+            Color::Rgb(1, 2, 3).downgrade(ColorMode::TrueColor),
+            Color::Rgb(1, 2, 3)
+        );
+    }
+}
@@ -0,0 +1,60 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        geometry::Constraint,
+        text::Text,
+        widgets::{Block, Element, Grad, Layout, Spacer, Span, Widget},
+    };
+
+    fn get_tree() -> Element {
+        let mut inner = Block::vertical().title("Inner");
+        inner.push(Grad::new("Grad", (0, 0, 0), (255, 255, 255)), 1);
+
+        let mut main = Layout::horizontal();
+        main.push("First", Constraint::Fill(1));
+        main.push(inner, Constraint::Fill(1));
+        main.push(Span::new("Second"), Constraint::Fill(1));
+        main.into()
+    }
+
+    /// Tests downcasting element to its wrapped widget
+    #[test]
+    fn element_downcast() {
+        let element: Element = Spacer::new().into();
+        assert!(element.downcast_ref::<Spacer>().is_some());
+        assert!(element.downcast_ref::<Span>().is_none());
+    }
+
+    /// Tests that container children are the wrapped widgets, not elements
+    #[test]
+    fn element_children() {
+        let tree = get_tree();
+        let layout = tree.downcast_ref::<Layout>().expect("Layout root");
+        let children = layout.children();
+        assert_eq!(children.len(), 3);
+
+        let first = children[0].downcast_ref::<Span>();
+        assert_eq!(first.map(|s| s.get_text()), Some("First"));
+        assert!(children[1].downcast_ref::<Block<Layout>>().is_some());
+    }
+
+    /// Tests finding nested widgets in the element tree
+    #[test]
+    fn element_find() {
+        let tree = get_tree();
+        assert!(tree.find::<Layout>().is_some());
+        assert!(tree.find::<Spacer>().is_none());
+
+        let grad = tree.find::<Grad>().expect("Grad should be found");
+        assert_eq!(grad.get_text(), "Grad");
+
+        let spans: Vec<&str> = tree
+            .find_all::<Span>()
+            .into_iter()
+            .map(|s| s.get_text())
+            .collect();
+        assert_eq!(spans, vec!["First", "Second"]);
+    }
+}
@@ -0,0 +1,49 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use termint::{
+        buffer::Buffer,
+        enums::{Color, Modifier},
+        geometry::{Rect, Vec2},
+        widgets::{Gauge, Widget},
+    };
+
+    /// Tests rendering gauge with percentage label
+    #[test]
+    fn gauge_percentage() {
+        let ratio = Rc::new(Cell::new(0.5));
+        let gauge = Gauge::new(ratio.clone());
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "███50%    ");
+
+        ratio.set(2.);
+        gauge.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "███100%███");
+    }
+
+    /// Tests label styling split at the fill edge
+    #[test]
+    fn gauge_label_style() {
+        let ratio = Rc::new(Cell::new(0.5));
+        let gauge = Gauge::new(ratio)
+            .label("abcd")
+            .ratio_style(Color::Green)
+            .track_style(Color::Red);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        gauge.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "████    \n██abcd  \n████    ");
+
+        for (x, inversed) in [(2, true), (3, true), (4, false), (5, false)] {
+            let cell = buffer.cell(&Vec2::new(x, 1)).unwrap();
+            let fg = if inversed { Color::Green } else { Color::Red };
+            assert_eq!(cell.fg, fg);
+            assert_eq!(cell.modifier.contains(Modifier::INVERSED), inversed);
+        }
+    }
+}
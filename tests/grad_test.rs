@@ -3,10 +3,12 @@ extern crate termint;
 #[cfg(test)]
 mod tests {
     use termint::{
-        enums::{Color, Modifier},
+        buffer::Buffer,
+        enums::{Color, Interpolation, Modifier, Wrap, RGB},
+        geometry::Rect,
         modifiers,
         text::Text,
-        widgets::Grad,
+        widgets::{Grad, Widget},
     };
 
     /// Tests creating new grad
@@ -34,7 +36,7 @@ mod tests {
             .modifier(modifiers!(BOLD, UNDERLINED))
             .bg(Color::White);
         let assert_val = format!(
-            "\x1b[1;34;1;4m{}{}G{}r{}a{}d{}i{}e{}n{}t\x1b[0m",
+            "\x1b[1;4m{}{}G{}r{}a{}d{}i{}e{}n{}t\x1b[0m",
             Color::White.to_bg(),
             Color::Rgb(0, 220, 255).to_fg(),
             Color::Rgb(25, 200, 255).to_fg(),
@@ -47,4 +49,84 @@ mod tests {
         );
         assert_eq!(grad.get(), assert_val);
     }
+
+    /// Tests multibyte ellipsis in a tight rect
+    #[test]
+    fn grad_ellipsis_multibyte() {
+        let grad =
+            Grad::new("Hello world", (0, 0, 0), (0, 0, 0)).ellipsis("…");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        grad.render(&mut buffer);
+
+        let text: String = buffer.content().iter().map(|c| c.val).collect();
+        assert_eq!(text, "Hello… ");
+    }
+
+    /// Tests letter wrapped wide text not fitting the area being truncated
+    /// by its display width
+    #[test]
+    fn grad_letter_wide() {
+        let grad =
+            Grad::new("中文中文", (0, 0, 0), (0, 0, 0)).wrap(Wrap::Letter);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        grad.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "... ");
+    }
+
+    /// Tests grad with multiple unsorted color stops
+    #[test]
+    fn grad_stops() {
+        let grad = Grad::stops(
+            "abcde",
+            vec![
+                (1.0, RGB::new(0, 0, 200)),
+                (0.0, RGB::new(200, 0, 0)),
+                (0.5, RGB::new(0, 200, 0)),
+            ],
+        );
+        let assert_val = format!(
+            "{}a{}b{}c{}d{}e\x1b[0m",
+            Color::Rgb(200, 0, 0).to_fg(),
+            Color::Rgb(100, 100, 0).to_fg(),
+            Color::Rgb(0, 200, 0).to_fg(),
+            Color::Rgb(0, 100, 100).to_fg(),
+            Color::Rgb(0, 0, 200).to_fg(),
+        );
+        assert_eq!(grad.get(), assert_val);
+    }
+
+    /// Tests grad with single color stop, which renders solid color
+    #[test]
+    fn grad_single_stop() {
+        let grad = Grad::stops("abc", vec![(2.0, RGB::new(10, 20, 30))]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        grad.render(&mut buffer);
+
+        for cell in buffer.content() {
+            assert_eq!(cell.fg, Color::Rgb(10, 20, 30));
+        }
+    }
+
+    /// Tests interpolating gradient in HSL color space
+    #[test]
+    fn grad_interpolate_hsl() {
+        let grad = Grad::new("abc", (0, 255, 255), (255, 0, 255))
+            .interpolate(Interpolation::Hsl);
+        let assert_val = format!(
+            "{}a{}b{}c\x1b[0m",
+            Color::Rgb(0, 255, 255).to_fg(),
+            Color::Rgb(0, 0, 255).to_fg(),
+            Color::Rgb(255, 0, 255).to_fg(),
+        );
+        assert_eq!(grad.get(), assert_val);
+    }
+
+    /// Tests that achromatic color doesn't change hue of the HSL gradient
+    #[test]
+    fn grad_interpolate_hsl_gray() {
+        let white = RGB::new(255, 255, 255);
+        let red = RGB::new(255, 0, 0);
+        assert_eq!(white.blend_hsl(red, 0.5), RGB::new(223, 159, 159));
+        assert_eq!(red.blend_hsl(white, 0.5), RGB::new(223, 159, 159));
+    }
 }
@@ -0,0 +1,124 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, Unit, Vec2},
+        widgets::{Grid, Widget},
+    };
+
+    /// Gets string representation of the given row of the buffer
+    fn row(buffer: &Buffer, y: usize) -> String {
+        (buffer.x()..=buffer.right())
+            .map(|x| buffer[(x, y)].val)
+            .collect()
+    }
+
+    /// Tests auto-placing children into the grid
+    #[test]
+    fn grid_push_auto() {
+        let mut grid = Grid::new([Unit::Length(2); 3], [Unit::Length(1); 2]);
+        for c in ["a", "b", "c", "d", "e", "f"] {
+            grid.push_auto(c);
+        }
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        grid.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "a b c ");
+        assert_eq!(row(&buffer, 1), "d e f ");
+    }
+
+    /// Tests auto-placement skips the cells occupied by explicit children
+    #[test]
+    fn grid_push_auto_skip() {
+        let mut grid = Grid::new([Unit::Length(2); 3], [Unit::Length(1); 2]);
+        grid.push("x", 1, 0);
+        grid.push_auto("a");
+        grid.push_auto("b");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        grid.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "a x b ");
+    }
+
+    /// Tests columns sized relatively to the rows
+    #[test]
+    fn grid_aspect() {
+        let mut grid = Grid::new([Unit::Aspect(2.0); 3], [Unit::Fill(1); 2]);
+        for c in ["a", "b", "c", "d", "e", "f"] {
+            grid.push_auto(c);
+        }
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 4));
+        grid.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "a   b   c       ");
+        assert_eq!(row(&buffer, 1), "                ");
+        assert_eq!(row(&buffer, 2), "d   e   f       ");
+        assert_eq!(grid.width(&Vec2::new(16, 4)), 12);
+
+        let mut grid = Grid::new([Unit::Length(3)], [Unit::Aspect(0.5); 2]);
+        grid.push_auto("a");
+        assert_eq!(grid.height(&Vec2::new(16, 4)), 4);
+    }
+
+    /// Tests that fill tracks take all the space left by the other tracks
+    #[test]
+    fn grid_fill() {
+        let units = [Unit::Length(2), Unit::Fill(1), Unit::Length(1)];
+        let mut grid = Grid::new(units, [Unit::Length(1)]);
+        for c in ["a", "b", "c"] {
+            grid.push_auto(c);
+        }
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        grid.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "a b    c");
+    }
+
+    /// Tests children spanning multiple columns and rows
+    #[test]
+    fn grid_push_span() {
+        let mut grid = Grid::new([Unit::Length(2); 3], [Unit::Length(1); 3]);
+        grid.push_span("header", 0, 0, 3, 1);
+        grid.push_span("ab cd", 0, 1, 1, 5);
+        grid.push_auto("x");
+        grid.push_auto("y");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        grid.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "header");
+        assert_eq!(row(&buffer, 1), "abx y ");
+        assert_eq!(row(&buffer, 2), "cd    ");
+    }
+
+    /// Tests positions of the children separated by gaps
+    #[test]
+    fn grid_gap() {
+        let mut grid = Grid::new([Unit::Fill(1); 2], [Unit::Length(1); 2])
+            .col_gap(1)
+            .row_gap(1);
+        for c in ["a", "b", "c", "d"] {
+            grid.push_auto(c);
+        }
+        assert_eq!(grid.height(&Vec2::new(7, 3)), 3);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        grid.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "a   b  ");
+        assert_eq!(row(&buffer, 1), "       ");
+        assert_eq!(row(&buffer, 2), "c   d  ");
+    }
+
+    /// Tests building grid in a single expression
+    #[test]
+    fn grid_with() {
+        let grid = Grid::new([Unit::Length(1); 2], [Unit::Length(1)])
+            .with("a", 1, 0)
+            .with("b", 0, 0);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        grid.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "ba");
+    }
+}
@@ -0,0 +1,235 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use termint::{
+        buffer::Buffer,
+        geometry::{Align, Constraint, Justify, Rect},
+        text::Text,
+        widgets::{Layout, Span, Widget},
+    };
+
+    /// Gets string representation of the given row of the buffer
+    fn row(buffer: &Buffer, y: usize) -> String {
+        (buffer.x()..=buffer.right())
+            .map(|x| buffer[(x, y)].val)
+            .collect()
+    }
+
+    /// Tests that content of `Min` children is measured the same way it's
+    /// rendered
+    #[test]
+    fn layout_min_measurement() {
+        let mut layout = Layout::vertical();
+        layout.push("aaaa bbbb cccc", Constraint::Min(0));
+        layout.push("dd ee", Constraint::Min(0));
+
+        let rect = Rect::new(1, 1, 10, 5);
+        assert_eq!(layout.height(rect.size()), 3);
+
+        let mut buffer = Buffer::empty(rect);
+        layout.render(&mut buffer);
+        assert_eq!(row(&buffer, 1), "aaaa bbbb ");
+        assert_eq!(row(&buffer, 2), "cccc      ");
+        assert_eq!(row(&buffer, 3), "dd ee     ");
+        assert_eq!(row(&buffer, 4), "          ");
+
+        // Child with size relative to the space it gets is measured using
+        // the whole layout size, not only the space left after the previous
+        // children, so the layout is rendered as high as it's measured
+        let mut inner = Layout::vertical();
+        inner.push("b", Constraint::Percent(50));
+
+        let mut layout = Layout::vertical();
+        layout.push("a", Constraint::Length(2));
+        layout.push(inner, Constraint::Min(0));
+        layout.push("c", Constraint::Length(1));
+
+        let rect = Rect::new(1, 1, 10, 8);
+        assert_eq!(layout.height(rect.size()), 7);
+
+        let mut buffer = Buffer::empty(rect);
+        layout.render(&mut buffer);
+        assert_eq!(row(&buffer, 3), "b         ");
+        assert_eq!(row(&buffer, 6), "          ");
+        assert_eq!(row(&buffer, 7), "c         ");
+    }
+
+    /// Tests that equal children split the space with the remainder
+    /// distributed from the first child
+    #[test]
+    fn layout_push_equal() {
+        let mut layout = Layout::horizontal();
+        layout.push_equal([Span::new("a"), Span::new("b"), Span::new("c")]);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        layout.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "a   b  c  ");
+    }
+
+    /// Tests that overflowing children are reported
+    #[test]
+    #[cfg(debug_assertions)]
+    fn layout_on_overflow() {
+        let needed = Rc::new(Cell::new(0));
+        let reported = needed.clone();
+
+        let mut layout = Layout::horizontal()
+            .on_overflow(move |needed, _| reported.set(needed));
+        layout.push("a", Constraint::Length(6));
+        layout.push("b", Constraint::Length(6));
+        layout.push("c", Constraint::Fill(1));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        layout.render(&mut buffer);
+        assert_eq!(needed.get(), 12);
+    }
+
+    /// Tests that children of the wrapping layout continue on the next row
+    #[test]
+    fn layout_wrap() {
+        let mut layout = Layout::horizontal().wrap();
+        layout.push("aa", Constraint::Min(0));
+        layout.push("bbb", Constraint::Length(4));
+        layout.push("cc", Constraint::Fill(1));
+        layout.push("dddddd", Constraint::Min(0));
+
+        let rect = Rect::new(0, 0, 6, 4);
+        assert_eq!(layout.height(rect.size()), 3);
+        assert_eq!(layout.width(rect.size()), 6);
+
+        let mut buffer = Buffer::empty(rect);
+        layout.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "aabbb ");
+        assert_eq!(row(&buffer, 1), "cc    ");
+        assert_eq!(row(&buffer, 2), "dddddd");
+        assert_eq!(row(&buffer, 3), "      ");
+    }
+
+    /// Tests that children of the vertical wrapping layout continue in the
+    /// next column
+    #[test]
+    fn layout_wrap_vertical() {
+        let mut layout = Layout::vertical().wrap();
+        layout.push("a", Constraint::Length(1));
+        layout.push("bb", Constraint::Length(1));
+        layout.push("c", Constraint::Length(1));
+
+        let rect = Rect::new(0, 0, 4, 2);
+        assert_eq!(layout.width(rect.size()), 3);
+        assert_eq!(layout.height(rect.size()), 2);
+
+        let mut buffer = Buffer::empty(rect);
+        layout.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "a c ");
+        assert_eq!(row(&buffer, 1), "bb  ");
+    }
+
+    /// Tests difference between `Length`, `Min`, `Max` and `MinMax` with
+    /// child with content of height 3
+    #[test]
+    fn layout_constraint_sizes() {
+        let size = |constraint: Constraint| {
+            let mut content = Layout::vertical();
+            for _ in 0..3 {
+                content.push("c", 1);
+            }
+            let mut layout = Layout::vertical();
+            layout.push(content, constraint);
+            layout.push("x", Constraint::Fill(1));
+
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 10));
+            layout.render(&mut buffer);
+            buffer.find("x")[0].y
+        };
+
+        assert_eq!(size(Constraint::Length(2)), 2);
+        assert_eq!(size(Constraint::Length(5)), 5);
+        assert_eq!(size(Constraint::Min(2)), 3);
+        assert_eq!(size(Constraint::Min(5)), 5);
+        assert_eq!(size(Constraint::Max(2)), 2);
+        assert_eq!(size(Constraint::Max(5)), 3);
+        assert_eq!(size(Constraint::MinMax(4, 5)), 4);
+    }
+
+    /// Tests distributing free space between the children
+    #[test]
+    fn layout_justify() {
+        let render = |justify: Justify, children: &[&str]| {
+            let mut layout = Layout::horizontal().justify(justify);
+            for child in children {
+                layout.push(*child, 2);
+            }
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+            layout.render(&mut buffer);
+            buffer.to_plain_string()
+        };
+
+        assert_eq!(render(Justify::Start, &["ab", "cd"]), "abcd      ");
+        assert_eq!(render(Justify::Center, &["ab", "cd"]), "   abcd   ");
+        assert_eq!(render(Justify::SpaceBetween, &["ab", "cd"]), "ab      cd");
+        assert_eq!(render(Justify::SpaceAround, &["ab", "cd"]), " ab   cd  ");
+        assert_eq!(render(Justify::SpaceEvenly, &["ab", "cd"]), "  ab  cd  ");
+        assert_eq!(render(Justify::SpaceBetween, &["ab"]), "    ab    ");
+    }
+
+    /// Tests aligning children on the cross axis
+    #[test]
+    fn layout_cross_align() {
+        let mut layout = Layout::vertical().cross_align(Align::Center);
+        layout.push("abcd", 1);
+        layout.push("fill", Constraint::Fill(1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        layout.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "   abcd   \nfill      ");
+
+        let mut layout = Layout::horizontal().cross_align(Align::End);
+        layout.push("a", 1);
+        layout.push("b", Constraint::Fill(1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 3));
+        layout.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), " b\n  \na ");
+    }
+
+    /// Tests inserting, getting and removing children of the layout
+    #[test]
+    fn layout_children_mutation() {
+        let mut layout = Layout::vertical();
+        layout.push("a", 1);
+        layout.push("c", 1);
+        assert_eq!(layout.len(), 2);
+
+        layout.insert(1, "b", 2);
+        assert_eq!(layout.len(), 3);
+        let b = layout.get(1).and_then(|c| c.downcast_ref::<Span>());
+        assert_eq!(b.map(|s| s.get_text()), Some("b"));
+        assert!(layout.get(3).is_none());
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 4));
+        layout.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "a\nb\n \nc");
+
+        assert!(layout.remove(1).is_some());
+        assert!(layout.remove(2).is_none());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 4));
+        layout.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "a\nc\n \n ");
+
+        layout.remove(0);
+        layout.remove(0);
+        assert!(layout.is_empty());
+    }
+
+    /// Tests building layout in a single expression
+    #[test]
+    fn layout_child_chain() {
+        let layout = Layout::vertical().child("a", 1).child("b", 1);
+        assert_eq!(layout.len(), 2);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 2));
+        layout.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "a\nb");
+    }
+}
@@ -0,0 +1,131 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use termint::{
+        buffer::Buffer,
+        enums::{Color, Modifier},
+        geometry::{Rect, Vec2},
+        style::Style,
+        widgets::{List, ListState, Scrollbar, ScrollbarState, Widget},
+    };
+
+    /// Tests that list renders the same scrollbar as the scrollbar widget
+    #[test]
+    fn list_scrollbar() {
+        let items: Vec<String> = (0..10).map(|i| i.to_string()).collect();
+        let state = Rc::new(RefCell::new(ListState::new(5)));
+        let list = List::new(items, state);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 5));
+        list.render(&mut buffer);
+
+        let sstate =
+            Rc::new(Cell::new(ScrollbarState::new(5).content_len(10)));
+        let mut sbuffer = Buffer::empty(Rect::new(2, 0, 1, 5));
+        Scrollbar::vertical(sstate).render(&mut sbuffer);
+
+        for y in 0..5 {
+            assert_eq!(buffer[(2, y)], sbuffer[(2, y)]);
+        }
+    }
+
+    /// Tests that filtered list shows only matching items and maps the
+    /// selected item to its original index
+    #[test]
+    fn list_filter() {
+        let items = vec!["Apple", "banana", "Cherry", "pineapple"];
+        let state = ListState::selected(0, 1).filter("APP".to_string());
+        let state = Rc::new(RefCell::new(state));
+        let list = List::new(items, state.clone()).highlight_symbol(">");
+
+        assert_eq!(list.visible_items(), vec![0, 3]);
+        assert_eq!(list.selected_item(), Some(3));
+        assert_eq!(list.height(&Vec2::new(10, 5)), 2);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        list.render(&mut buffer);
+        assert_eq!(
+            buffer.to_plain_string(),
+            " Apple    \n>pineapple\n          "
+        );
+
+        state.borrow_mut().filter = None;
+        assert_eq!(list.selected_item(), Some(1));
+    }
+
+    /// Tests that items use their own style with the selected style on top
+    #[test]
+    fn list_styled() {
+        let items = vec![
+            ("error", Style::new().fg(Color::Red)),
+            ("warning", Style::new().fg(Color::Yellow)),
+        ];
+        let state = Rc::new(RefCell::new(ListState::selected(0, 1)));
+        let list = List::styled(items, state).selected_style(
+            Style::new().bg(Color::Blue).modifier(Modifier::BOLD),
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        list.render(&mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, Color::Red);
+        assert_eq!(buffer[(0, 0)].bg, Color::Default);
+        assert_eq!(buffer[(0, 1)].fg, Color::Yellow);
+        assert_eq!(buffer[(0, 1)].bg, Color::Blue);
+        assert!(buffer[(0, 1)].modifier.contains(Modifier::BOLD));
+    }
+
+    /// Tests horizontal list layout, selection and scrolling
+    #[test]
+    fn list_horizontal() {
+        let items = ["One", "Two", "Three"];
+        let state = Rc::new(RefCell::new(ListState::selected(0, 1)));
+        let list = List::horizontal(items, state.clone())
+            .highlight_symbol(">")
+            .selected_style(Color::Yellow)
+            .auto_scroll();
+
+        assert_eq!(list.width(&Vec2::new(20, 5)), 16);
+        assert_eq!(list.height(&Vec2::new(20, 5)), 1);
+        assert_eq!(list.height(&Vec2::new(10, 5)), 2);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+        list.render(&mut buffer);
+        assert_eq!(
+            buffer.to_plain_string(),
+            " One >Two  Three    \n                    "
+        );
+        assert_eq!(buffer[(6, 0)].fg, Color::Yellow);
+
+        // Scrolls to the selected item and shows the scrollbar below
+        state.borrow_mut().selected = Some(2);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        list.render(&mut buffer);
+        assert_eq!(state.borrow().offset, 2);
+        assert_eq!(&buffer.to_plain_string()[..10], ">Three    ");
+        assert!(!buffer.find("━").is_empty());
+
+        // Single row has no space for the scrollbar
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        list.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), ">Three    ");
+    }
+
+    /// Tests scrolling to horizontal list item wider than the list
+    #[test]
+    fn list_horizontal_wide_item() {
+        let state = Rc::new(RefCell::new(ListState::selected(0, 1)));
+        let list = List::horizontal(["ab", "a very long item"], state.clone())
+            .auto_scroll();
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        list.render(&mut buffer);
+        assert_eq!(state.borrow().offset, 1);
+        assert_eq!(&buffer.to_plain_string()[..6], "a very");
+    }
+}
@@ -0,0 +1,71 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        enums::{Color, Modifier},
+        geometry::{Rect, Vec2},
+        text::markup,
+        widgets::Widget,
+    };
+
+    fn render(text: &str) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 24, 1));
+        markup(text).render(&mut buffer);
+        buffer
+    }
+
+    /// Tests that wide text not fitting the area is truncated with ellipsis
+    /// and doesn't grow the buffer
+    #[test]
+    fn markup_wide_clip() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        markup("[red]中文中文[/] x").render(&mut buffer);
+        assert_eq!(*buffer.rect(), Rect::new(0, 0, 4, 1));
+        assert_eq!(buffer.to_plain_string(), " ...");
+    }
+
+    /// Tests styling text using markup tags
+    #[test]
+    fn markup_styles() {
+        let buffer = render("[red]Error:[/] file [bold on_blue]missing[/]");
+        assert_eq!(buffer.to_plain_string(), "Error: file missing     ");
+
+        assert_eq!(buffer[Vec2::new(0, 0)].fg, Color::Red);
+        assert_eq!(buffer[Vec2::new(7, 0)].fg, Color::Default);
+        let cell = &buffer[Vec2::new(12, 0)];
+        assert!(cell.modifier.contains(Modifier::BOLD));
+        assert_eq!(cell.bg, Color::Blue);
+    }
+
+    /// Tests nested tags, unknown tags and escaped brackets
+    #[test]
+    fn markup_nested() {
+        let buffer = render("[red][italic]a[/]b[/] [foo]c [[d] [/]");
+        assert_eq!(buffer.to_plain_string(), "ab [foo]c [d] [/]       ");
+
+        let a = &buffer[Vec2::new(0, 0)];
+        assert_eq!(a.fg, Color::Red);
+        assert!(a.modifier.contains(Modifier::ITALIC));
+        let b = &buffer[Vec2::new(1, 0)];
+        assert_eq!(b.fg, Color::Red);
+        assert!(!b.modifier.contains(Modifier::ITALIC));
+        assert_eq!(buffer[Vec2::new(3, 0)].fg, Color::Default);
+    }
+
+    /// Tests that whitespace around the tags is kept as written
+    #[test]
+    fn markup_whitespace() {
+        let buffer = render("foo[bold]bar[/]baz");
+        assert_eq!(buffer.to_plain_string(), "foobarbaz               ");
+
+        let buffer = render("a  [red]b[/]  c");
+        assert_eq!(buffer.to_plain_string(), "a  b  c                 ");
+        assert_eq!(buffer[Vec2::new(3, 0)].fg, Color::Red);
+
+        let mut buffer = Buffer::empty(Rect::new(2, 1, 8, 1));
+        markup("a [red]b[/] c").render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "a b c   ");
+    }
+}
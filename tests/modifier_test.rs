@@ -0,0 +1,47 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{enums::Modifier, modifiers};
+
+    /// Tests querying and toggling modifier flags
+    #[test]
+    fn modifier_toggle_contains() {
+        let mut modifier = Modifier::empty();
+        modifier.add(Modifier::BOLD);
+        modifier.add(Modifier::BOLD);
+        assert!(modifier.contains(Modifier::BOLD));
+        assert!(!modifier.contains(Modifier::BOLD | Modifier::ITALIC));
+
+        modifier.toggle(Modifier::BOLD | Modifier::ITALIC);
+        assert!(!modifier.contains(Modifier::BOLD));
+        assert!(modifier.contains(Modifier::ITALIC));
+
+        modifier.toggle(Modifier::ITALIC);
+        assert!(modifier.is_empty());
+        assert_eq!(modifier.into_iter().next(), None);
+    }
+
+    /// Tests SGR codes of combined modifiers, including extended ones
+    #[test]
+    fn modifier_display() {
+        let mut modifier = Modifier::empty();
+        assert_eq!(modifier.to_string(), "");
+
+        modifier.add(modifiers!(BOLD, STRIKED, DOUBLE_UNDERLINED));
+        assert_eq!(modifier.to_string(), "\x1b[1;9;21m");
+
+        let flags: Vec<u16> = modifier.into_iter().collect();
+        assert_eq!(
+            flags,
+            vec![
+                Modifier::BOLD,
+                Modifier::STRIKED,
+                Modifier::DOUBLE_UNDERLINED
+            ]
+        );
+
+        modifier.sub(Modifier::DOUBLE_UNDERLINED);
+        assert_eq!(modifier.to_string(), "\x1b[1;9m");
+    }
+}
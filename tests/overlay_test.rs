@@ -0,0 +1,110 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        enums::{Color, Modifier},
+        geometry::{Align, Constraint, Rect, Vec2},
+        style::Style,
+        widgets::{Block, Overlay, Widget},
+    };
+
+    fn row(buffer: &Buffer, y: usize) -> String {
+        (0..buffer.width())
+            .map(|x| buffer.cell(&Vec2::new(x, y)).unwrap().val)
+            .collect()
+    }
+
+    /// Tests centered child on top of the full size child
+    #[test]
+    fn overlay_aligned_center() {
+        let mut overlay = Overlay::new(vec![Block::vertical().into()]);
+        let center = Align::Center;
+        overlay.push_aligned(Block::vertical(), (4, 3), center, center);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 5));
+        overlay.render(&mut buffer);
+        assert_eq!(row(&buffer, 0), "┌──────┐");
+        assert_eq!(row(&buffer, 1), "│ ┌──┐ │");
+        assert_eq!(row(&buffer, 2), "│ │  │ │");
+        assert_eq!(row(&buffer, 3), "│ └──┘ │");
+        assert_eq!(row(&buffer, 4), "└──────┘");
+    }
+
+    /// Tests aligned child bigger than the overlay being clamped
+    #[test]
+    fn overlay_aligned_clamp() {
+        let mut overlay = Overlay::new(vec![]);
+        overlay.push_aligned(
+            Block::vertical(),
+            (10, Constraint::Percent(50)),
+            Align::End,
+            Align::End,
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        overlay.render(&mut buffer);
+        assert_eq!(row(&buffer, 1), "    ");
+        assert_eq!(row(&buffer, 2), "┌──┐");
+        assert_eq!(row(&buffer, 3), "└──┘");
+    }
+
+    /// Tests dimming content below the top child and its shadow
+    #[test]
+    fn overlay_dim_shadow() {
+        let dim = Style::new().modifier(Modifier::DIM);
+        let mut overlay = Overlay::new(vec![Block::vertical().into()])
+            .dim_below(dim)
+            .shadow(Style::new().bg(Color::Black));
+        let center = Align::Center;
+        overlay.push_aligned(Block::vertical(), (4, 2), center, center);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 5));
+        overlay.render(&mut buffer);
+
+        // Characters below are kept, only their style changes
+        assert_eq!(row(&buffer, 0), "┌──────┐");
+        assert_eq!(row(&buffer, 4), "└──────┘");
+        assert!(buffer[(0, 0)].modifier.contains(Modifier::DIM));
+        assert!(!buffer[(2, 1)].modifier.contains(Modifier::DIM));
+
+        // Shadow is offset by one cell right and down
+        assert_eq!(buffer[(6, 2)].bg, Color::Black);
+        assert_eq!(buffer[(3, 3)].bg, Color::Black);
+        assert_eq!(buffer[(2, 3)].bg, Color::Default);
+        assert_eq!(buffer[(6, 1)].bg, Color::Default);
+        assert_eq!(row(&buffer, 3), "│      │");
+    }
+
+    /// Tests that shadow of the child on the edge is clipped to the overlay
+    #[test]
+    fn overlay_shadow_edge() {
+        let mut overlay =
+            Overlay::new(vec![]).shadow(Style::new().bg(Color::Black));
+        overlay.push_aligned(
+            Block::vertical(),
+            (1, 3),
+            Align::End,
+            Align::End,
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 5));
+        overlay.render(&mut buffer);
+        assert!(buffer.content().iter().all(|c| c.bg == Color::Default));
+
+        let mut overlay =
+            Overlay::new(vec![]).shadow(Style::new().bg(Color::Black));
+        overlay.push_aligned(
+            Block::vertical(),
+            (2, 3),
+            Align::End,
+            Align::Center,
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 5));
+        overlay.render(&mut buffer);
+        assert_eq!(buffer[(7, 4)].bg, Color::Black);
+        assert_eq!(buffer[(6, 4)].bg, Color::Default);
+    }
+}
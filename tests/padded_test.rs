@@ -0,0 +1,23 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, Vec2},
+        widgets::{Padded, StrSpanExtension, Widget},
+    };
+
+    /// Tests rendering and measuring padded widget
+    #[test]
+    fn padded_render() {
+        let padded = Padded::new("abc".to_span(), (1, 2));
+        assert_eq!(padded.width(&Vec2::new(20, 3)), 7);
+        assert_eq!(padded.height(&Vec2::new(20, 10)), 3);
+        assert_eq!(padded.children().len(), 1);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        padded.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "       \n  abc  \n       ");
+    }
+}
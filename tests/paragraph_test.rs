@@ -5,6 +5,13 @@ extern crate termint;
 
 #[cfg(test)]
 mod tests {
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, TextAlign, Vec2},
+        paragraph,
+        widgets::{Paragraph, StrSpanExtension, Widget},
+    };
+
     // use termint::{
     //     enums::{modifier::Modifier, Color},
     //     widgets::{paragraph::Paragraph, span::StrSpanExtension},
@@ -25,4 +32,52 @@ mod tests {
         // p.add("Test".fg(Color::Black).bg(Color::White));
         // assert_eq!(p.get(), "\x1b[30m\x1b[107mTest\x1b[0m");
     }
+
+    /// Tests aligning lines of the paragraph
+    #[test]
+    fn paragraph_align() {
+        let render = |align| {
+            let p = paragraph!("Hello".to_span(), "big world".to_span())
+                .align(align);
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+            p.render(&mut buffer);
+            buffer.to_plain_string()
+        };
+
+        assert_eq!(render(TextAlign::Left), "Hello big   \nworld       ");
+        assert_eq!(render(TextAlign::Center), " Hello big  \n   world    ");
+        assert_eq!(render(TextAlign::Right), "   Hello big\n       world");
+    }
+
+    /// Tests that paragraph height matches the rendered lines
+    #[test]
+    fn paragraph_height_word_wrap() {
+        let p = paragraph!("Ωμέγα αβγ".to_span(), "δε".to_span());
+        assert_eq!(p.height(&Vec2::new(9, 5)), 2);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        p.render(&mut buffer);
+        assert_eq!(
+            buffer.to_plain_string(),
+            "Ωμέγα αβγ\nδε       \n         "
+        );
+
+        let p = paragraph!("ab\ncd".to_span(), "ef".to_span());
+        assert_eq!(p.height(&Vec2::new(10, 5)), 2);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        p.render(&mut buffer);
+        assert_eq!(
+            buffer.to_plain_string(),
+            "ab        \ncd ef     \n          "
+        );
+        assert_eq!(p.width(&Vec2::new(10, 2)), 5);
+
+        let p = paragraph!("eeeeeeeeeeeeeee a".to_span(), "f".to_span());
+        assert_eq!(p.height(&Vec2::new(13, 5)), 1);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 2));
+        p.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "... f        \n             ");
+    }
 }
@@ -0,0 +1,69 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::geometry::{map_point, Rect, Vec2};
+
+    /// Tests expanding and shrinking rect by a margin
+    #[test]
+    fn rect_expand_shrink() {
+        let rect = Rect::new(5, 5, 10, 4);
+        assert_eq!(rect.expand(2), Rect::new(3, 3, 14, 8));
+        assert_eq!(rect.shrink(1), Rect::new(6, 6, 8, 2));
+        assert_eq!(rect.expand(2).shrink(2), rect);
+
+        // Expanding is clamped at the origin
+        let rect = Rect::new(1, 0, 3, 3);
+        assert_eq!(rect.expand(2), Rect::new(0, 0, 6, 5));
+    }
+
+    /// Tests mapping logical coordinates to cells within rect
+    #[test]
+    fn rect_map_point() {
+        let rect = Rect::new(2, 1, 5, 3);
+        let bounds = (-1.0, 1.0, 0.0, 10.0);
+
+        assert_eq!(
+            map_point(&rect, -1.0, 10.0, bounds),
+            Some(Vec2::new(2, 1))
+        );
+        assert_eq!(map_point(&rect, 1.0, 0.0, bounds), Some(Vec2::new(6, 3)));
+        assert_eq!(map_point(&rect, 0.0, 5.0, bounds), Some(Vec2::new(4, 2)));
+
+        // Points outside of the bounds are clipped
+        assert_eq!(map_point(&rect, 1.5, 5.0, bounds), None);
+        assert_eq!(map_point(&rect, 0.0, -0.1, bounds), None);
+        assert_eq!(map_point(&rect, f64::NAN, 5.0, bounds), None);
+
+        // Empty bounds and empty rect can't be mapped to
+        assert_eq!(map_point(&rect, 0.0, 0.0, (0.0, 0.0, 0.0, 1.0)), None);
+        assert_eq!(map_point(&Rect::new(0, 0, 0, 3), 0.0, 5.0, bounds), None);
+    }
+
+    /// Tests whether rects intersect
+    #[test]
+    fn rect_intersects() {
+        let rect = Rect::new(2, 2, 4, 3);
+
+        // Overlapping in x, but disjoint in y
+        assert!(!rect.intersects(&Rect::new(3, 6, 2, 2)));
+        // Overlapping in y, but disjoint in x
+        assert!(!rect.intersects(&Rect::new(7, 3, 2, 2)));
+        // Completely disjoint
+        assert!(!rect.intersects(&Rect::new(10, 10, 1, 1)));
+
+        // Touching edges share cells
+        assert!(rect.intersects(&Rect::new(5, 4, 2, 2)));
+        assert!(rect.intersects(&Rect::new(0, 0, 3, 3)));
+        assert!(!rect.intersects(&Rect::new(6, 2, 2, 2)));
+
+        // Containment
+        assert!(rect.intersects(&Rect::new(3, 3, 1, 1)));
+        assert!(Rect::new(3, 3, 1, 1).intersects(&rect));
+        assert!(rect.intersects(&rect));
+
+        // Empty rect doesn't intersect
+        assert!(!rect.intersects(&Rect::new(3, 3, 0, 1)));
+        assert!(!Rect::new(0, 0, 0, 0).intersects(&Rect::new(0, 0, 1, 1)));
+    }
+}
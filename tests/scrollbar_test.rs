@@ -0,0 +1,130 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, Vec2},
+        widgets::{Scrollbar, ScrollbarState, Widget},
+    };
+
+    /// Tests that the thumb stays visible for very long content
+    #[test]
+    fn scrollbar_min_thumb() {
+        let state =
+            Rc::new(Cell::new(ScrollbarState::new(0).content_len(100000)));
+        let scrollbar = Scrollbar::vertical(state);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 10));
+        scrollbar.render(&mut buffer);
+        let thumb = buffer.content().iter().filter(|c| c.val == '┃').count();
+        assert_eq!(thumb, 1);
+    }
+
+    /// Tests getting offset from click on the thumb and outside of it
+    #[test]
+    fn scrollbar_offset_at() {
+        let state =
+            Rc::new(Cell::new(ScrollbarState::new(0).content_len(100)));
+        let scrollbar = Scrollbar::vertical(state.clone());
+        let rect = Rect::new(5, 2, 1, 10);
+
+        // Page scroll below the thumb
+        assert_eq!(scrollbar.offset_at(Vec2::new(5, 8), rect), 10);
+
+        // Clicking the bottom of the track jumps to the end
+        assert_eq!(scrollbar.offset_at(Vec2::new(5, 11), rect), 90);
+
+        // Dragging the thumb to the bottom of the track
+        state.set(state.get().offset(85));
+        assert_eq!(scrollbar.offset_at(Vec2::new(5, 11), rect), 90);
+
+        // Page scroll above the thumb
+        assert_eq!(scrollbar.offset_at(Vec2::new(5, 3), rect), 75);
+
+        // Clicking the top of the track jumps to the start
+        assert_eq!(scrollbar.offset_at(Vec2::new(5, 2), rect), 0);
+    }
+
+    /// Tests clicking on the top half of the thumb at the start of the track
+    #[test]
+    fn scrollbar_offset_at_thumb_start() {
+        let state = Rc::new(Cell::new(ScrollbarState::new(0).content_len(20)));
+        let scrollbar = Scrollbar::vertical(state);
+        let rect = Rect::new(0, 0, 1, 10);
+
+        assert_eq!(scrollbar.offset_at(Vec2::new(0, 0), rect), 0);
+        assert_eq!(scrollbar.offset_at(Vec2::new(0, 1), rect), 0);
+    }
+
+    /// Tests clicking on scrollbar with empty rect or short content
+    #[test]
+    fn scrollbar_offset_at_empty() {
+        let state = Rc::new(Cell::new(ScrollbarState::new(0).content_len(20)));
+        let scrollbar = Scrollbar::vertical(state.clone());
+
+        assert_eq!(
+            scrollbar.offset_at(Vec2::new(0, 0), Rect::new(0, 0, 0, 0)),
+            0
+        );
+        assert_eq!(
+            scrollbar.offset_at(Vec2::new(0, 0), Rect::new(0, 0, 1, 0)),
+            0
+        );
+
+        state.set(state.get().content_len(5));
+        assert_eq!(
+            scrollbar.offset_at(Vec2::new(0, 9), Rect::new(0, 0, 1, 10)),
+            0
+        );
+    }
+
+    /// Tests scrolling the state by pages
+    #[test]
+    fn scrollbar_state_page() {
+        let mut state = ScrollbarState::new(0).content_len(100);
+        state.page_down(20);
+        assert_eq!(state.offset, 20);
+
+        // Last page is clamped, so it stays full
+        state.offset = 70;
+        state.page_down(20);
+        assert_eq!(state.offset, 80);
+        state.page_down(20);
+        assert_eq!(state.offset, 80);
+
+        state.page_up(20);
+        assert_eq!(state.offset, 60);
+        state.offset = 10;
+        state.page_up(20);
+        assert_eq!(state.offset, 0);
+
+        // Content shorter than the viewport doesn't scroll
+        let mut state = ScrollbarState::new(0).content_len(5);
+        state.page_down(20);
+        assert_eq!(state.offset, 0);
+
+        // Offset past the last full page isn't moved back
+        let mut state = ScrollbarState::new(0).content_len(100);
+        state.last();
+        state.page_down(20);
+        assert_eq!(state.offset, 99);
+    }
+
+    /// Tests rendering scrollbar into buffer with no space
+    #[test]
+    fn scrollbar_render_empty() {
+        let state = Rc::new(Cell::new(ScrollbarState::new(0).content_len(20)));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 0));
+        Scrollbar::vertical(state.clone()).render(&mut buffer);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 0, 10));
+        Scrollbar::vertical(state.clone()).render(&mut buffer);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 0, 1));
+        Scrollbar::horizontal(state).render(&mut buffer);
+    }
+}
@@ -0,0 +1,36 @@
+extern crate termint;
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use termint::{
+        enums::{Color, Modifier},
+        modifiers,
+        style::Style,
+    };
+
+    /// Tests style with modifiers and RGB foreground surviving round-trip
+    #[test]
+    fn style_round_trip() {
+        let style = Style::new()
+            .fg(Color::Rgb(10, 20, 30))
+            .modifier(modifiers!(BOLD, ITALIC));
+
+        let json = serde_json::to_string(&style).unwrap();
+        let res: Style = serde_json::from_str(&json).unwrap();
+        assert_eq!(res, style);
+    }
+
+    /// Tests modifier serializing as flag names and deserializing from u8
+    #[test]
+    fn modifier_serde() {
+        let mut modifier = Modifier::empty();
+        modifier.add(Modifier::BOLD | Modifier::ITALIC);
+
+        let json = serde_json::to_string(&modifier).unwrap();
+        assert_eq!(json, r#"["bold","italic"]"#);
+
+        let res: Modifier = serde_json::from_str("5").unwrap();
+        assert_eq!(res, modifier);
+        assert!(serde_json::from_str::<Modifier>(r#"["bolder"]"#).is_err());
+    }
+}
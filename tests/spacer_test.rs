@@ -0,0 +1,51 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        buffer::Buffer,
+        enums::Color,
+        geometry::{Constraint, Rect},
+        widgets::{Layout, Spacer, Widget},
+    };
+
+    /// Tests that spacer renders nothing by default
+    #[test]
+    fn spacer_empty() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer.set_str("abc", &(0, 0).into());
+        Spacer::new().render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "abc");
+    }
+
+    /// Tests spacer used as dotted leader between label and value
+    #[test]
+    fn spacer_fill() {
+        let mut layout = Layout::horizontal();
+        layout.push("Size", Constraint::Min(0));
+        let spacer = Spacer::new().fill_char('.').style(Color::Gray);
+        layout.push(spacer, Constraint::Fill(1));
+        layout.push("42", Constraint::Min(0));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        layout.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "Size....42");
+        assert_eq!(buffer[(5, 0)].fg, Color::Gray);
+    }
+
+    /// Tests filled spacer that got no space in the layout
+    #[test]
+    fn spacer_fill_no_space() {
+        let mut layout = Layout::horizontal();
+        layout.push("Size42", Constraint::Min(0));
+        let spacer = Spacer::new().fill_char('.').style(Color::Gray);
+        layout.push(spacer, Constraint::Fill(1));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        layout.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "Size42");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 0, 2));
+        Spacer::new().fill_char('.').render(&mut buffer);
+    }
+}
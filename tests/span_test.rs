@@ -6,10 +6,12 @@ extern crate termint;
 #[cfg(test)]
 mod tests {
     use termint::{
-        enums::{Color, Modifier},
+        buffer::{Buffer, Link},
+        enums::{Color, EllipsisMode, Modifier, Wrap},
+        geometry::{Rect, TextAlign, Vec2},
         modifiers,
         text::Text,
-        widgets::{Span, StrSpanExtension},
+        widgets::{Span, StrSpanExtension, Widget},
     };
 
     /// Tests creating new span
@@ -80,17 +82,17 @@ mod tests {
                 | Modifier::ITALIC
                 | Modifier::INVERSED,
         );
-        assert_eq!(span.get(), "\x1b[1;34;1;3;5;7mSpan modifier\x1b[0m");
+        assert_eq!(span.get(), "\x1b[1;3;5;7mSpan modifier\x1b[0m");
 
         // Creates span from &str
         let span = "Span modifier"
             .modifier(modifiers!(BOLD, BLINK, ITALIC, INVERSED));
-        assert_eq!(span.get(), "\x1b[1;34;1;3;5;7mSpan modifier\x1b[0m");
+        assert_eq!(span.get(), "\x1b[1;3;5;7mSpan modifier\x1b[0m");
 
         // Using modifiers macro
         let span = "Span modifier"
             .modifier(modifiers!(BOLD, BLINK, ITALIC, INVERSED));
-        assert_eq!(span.get(), "\x1b[1;34;1;3;5;7mSpan modifier\x1b[0m");
+        assert_eq!(span.get(), "\x1b[1;3;5;7mSpan modifier\x1b[0m");
     }
 
     /// Tests setting both fg and bg with RGB values
@@ -114,4 +116,150 @@ mod tests {
             "\x1b[38;2;0;150;150m\x1b[48;2;255;255;0mSpan fg bg\x1b[0m"
         );
     }
+
+    /// Tests span with hyperlink
+    #[test]
+    fn span_link() {
+        let url = "https://github.com/Martan03/termint";
+        let span = Span::new("Termint").link(url);
+        assert_eq!(
+            span.get(),
+            format!("\x1b]8;;{url}\x1b\\Termint\x1b]8;;\x1b\\\x1b[0m")
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        span.render(&mut buffer);
+        let link = Some(Link::new(url));
+        for x in 0..7 {
+            assert_eq!(buffer.cell(&Vec2::new(x, 0)).unwrap().link, link);
+        }
+        assert_eq!(buffer.cell(&Vec2::new(7, 0)).unwrap().link, None);
+    }
+
+    /// Tests creating span from owned strings
+    #[test]
+    fn span_from_string() {
+        let text = format!("Value {}", 42);
+        assert_eq!((&text).fg(Color::Red).get(), "\x1b[91mValue 42\x1b[0m");
+        assert_eq!(text.fg(Color::Red).get(), "\x1b[91mValue 42\x1b[0m");
+    }
+
+    /// Tests letter wrap height of text ending with newlines
+    #[test]
+    fn span_letter_height_newline() {
+        let size = Vec2::new(3, 10);
+        assert_eq!(Span::new("abcd").wrap(Wrap::Letter).height(&size), 2);
+        assert_eq!(Span::new("a\n").wrap(Wrap::Letter).height(&size), 1);
+        assert_eq!(Span::new("a\n\n").wrap(Wrap::Letter).height(&size), 2);
+        assert_eq!(Span::new("abcd\n\n").wrap(Wrap::Letter).height(&size), 3);
+    }
+
+    /// Tests multibyte ellipsis in a tight rect
+    #[test]
+    fn span_ellipsis_multibyte() {
+        let span = Span::new("Hello world").ellipsis("…");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        span.render_offset(&mut buffer, 0, None);
+
+        let text: String = buffer.content().iter().map(|c| c.val).collect();
+        assert_eq!(text, "Hello… ");
+    }
+
+    /// Tests rendering justified paragraph
+    #[test]
+    fn span_justify() {
+        let span = "The quick brown fox jumps over the lazy dog"
+            .align(TextAlign::Justify);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 3));
+        span.render(&mut buffer);
+
+        let row = |y| -> String {
+            (0..16).map(|x| buffer[Vec2::new(x, y)].val).collect()
+        };
+        assert_eq!(row(0), "The  quick brown");
+        assert_eq!(row(1), "fox  jumps  over");
+        assert_eq!(row(2), "the lazy dog    ");
+    }
+
+    /// Tests breaking word longer than the line when word wrapping
+    #[test]
+    fn span_word_break() {
+        let span = Span::new("supercalifragilistic is long");
+        assert_eq!(span.height(&Vec2::new(5, 10)), 6);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 6));
+        span.render(&mut buffer);
+        assert_eq!(
+            buffer.to_plain_string(),
+            "super\ncalif\nragil\nistic\nis   \nlong "
+        );
+    }
+
+    /// Tests aligning text containing wide characters by its display width
+    #[test]
+    fn span_align_wide() {
+        let span = "中ab".align(TextAlign::Center);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        span.render(&mut buffer);
+        assert_eq!(buffer.find("中"), vec![Vec2::new(3, 0)]);
+        assert_eq!(buffer.to_plain_string(), "   中ab   ");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        span.render_offset(&mut buffer, 0, None);
+        assert_eq!(buffer.find("中"), vec![Vec2::new(3, 0)]);
+
+        let span = "ab中".align(TextAlign::Right);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        span.render(&mut buffer);
+        assert_eq!(buffer.find("ab"), vec![Vec2::new(6, 0)]);
+    }
+
+    /// Tests truncating text at the end, start and middle
+    #[test]
+    fn span_ellipsis_mode() {
+        let render = |span: Span, width, height| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+            span.render_offset(&mut buffer, 0, None);
+            buffer.to_plain_string()
+        };
+        let span = |mode| {
+            Span::new("Hello wide world")
+                .ellipsis("…")
+                .ellipsis_mode(mode)
+        };
+
+        assert_eq!(render(span(EllipsisMode::End), 12, 1), "Hello wide… ");
+        assert_eq!(render(span(EllipsisMode::Start), 12, 1), "… wide world");
+        assert_eq!(render(span(EllipsisMode::Middle), 12, 1), "Hello …world");
+
+        // Truncates only the last visible row
+        assert_eq!(
+            render(span(EllipsisMode::Middle), 7, 2),
+            "Hello  \nwid…rld"
+        );
+
+        let path = "/very/long/path/file.txt"
+            .ellipsis("…")
+            .ellipsis_mode(EllipsisMode::Middle);
+        assert_eq!(render(path, 12, 1), "/very/…e.txt");
+
+        // Letter wrap continues on the next rows
+        let letters = |mode| {
+            "abcdefghij"
+                .wrap(Wrap::Letter)
+                .ellipsis("…")
+                .ellipsis_mode(mode)
+        };
+        assert_eq!(render(letters(EllipsisMode::End), 4, 2), "abcd\nefg…");
+        assert_eq!(render(letters(EllipsisMode::Start), 4, 2), "…def\nghij");
+        assert_eq!(render(letters(EllipsisMode::Middle), 4, 2), "abcd\n…hij");
+    }
+
+    /// Tests that word wrap height uses display width of the words
+    #[test]
+    fn span_multibyte_height() {
+        let span = Span::new("Café Café");
+        assert_eq!(span.height(&Vec2::new(9, 5)), 1);
+        assert_eq!(span.height(&Vec2::new(8, 5)), 2);
+    }
 }
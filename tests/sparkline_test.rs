@@ -0,0 +1,40 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, Vec2},
+        widgets::{Sparkline, Widget},
+    };
+
+    fn row(buffer: &Buffer) -> String {
+        buffer.content().iter().map(|c| c.val).collect()
+    }
+
+    /// Tests sparkline scaling and left padding
+    #[test]
+    fn sparkline_render() {
+        let sparkline = Sparkline::new(&[0, 7, 3, 14]);
+        assert_eq!(sparkline.width(&Vec2::new(10, 1)), 4);
+        assert_eq!(sparkline.height(&Vec2::new(10, 1)), 1);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        sparkline.render(&mut buffer);
+        assert_eq!(row(&buffer), "  ▁▄▂█");
+    }
+
+    /// Tests sparkline showing only the most recent values with pinned max
+    #[test]
+    fn sparkline_recent_max() {
+        let data = Rc::new(RefCell::new(vec![100, 0, 14, 7]));
+        let sparkline = Sparkline::shared(data.clone()).max(28);
+        data.borrow_mut().push(28);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        sparkline.render(&mut buffer);
+        assert_eq!(row(&buffer), "▁▄▂█");
+    }
+}
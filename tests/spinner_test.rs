@@ -0,0 +1,44 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use termint::{
+        buffer::Buffer,
+        enums::Color,
+        geometry::{Rect, Vec2},
+        widgets::{Spinner, Widget},
+    };
+
+    /// Tests that spinner renders frame based on the tick
+    #[test]
+    fn spinner_frames() {
+        let tick = Rc::new(Cell::new(0));
+        let spinner = Spinner::new(tick.clone())
+            .frames(Spinner::LINE)
+            .style(Color::Cyan);
+        assert_eq!(spinner.width(&Vec2::new(10, 10)), 1);
+        assert_eq!(spinner.height(&Vec2::new(10, 10)), 1);
+
+        let mut buffer = Buffer::empty(Rect::new(2, 1, 3, 1));
+        spinner.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "|  ");
+        assert_eq!(buffer[(2, 1)].fg, Color::Cyan);
+
+        tick.set(6);
+        spinner.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "-  ");
+    }
+
+    /// Tests that spinner without frames renders nothing
+    #[test]
+    fn spinner_no_frames() {
+        let spinner = Spinner::new(Rc::new(Cell::new(3))).frames(vec![]);
+        assert_eq!(spinner.frame(), None);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        spinner.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), " ");
+    }
+}
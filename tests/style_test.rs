@@ -0,0 +1,30 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use termint::{
+        enums::{Color, Modifier},
+        style::Style,
+    };
+
+    /// Tests merging styles, where set colors of the top style take
+    /// precedence and modifiers are combined
+    #[test]
+    fn style_merge() {
+        let base = Style::new().fg(Color::Red).modifier(Modifier::BOLD);
+        let over = Style::new().bg(Color::Blue).modifier(Modifier::ITALIC);
+
+        let merged = base.merge(&over);
+        assert_eq!(merged.fg, Some(Color::Red));
+        assert_eq!(merged.bg, Some(Color::Blue));
+        assert!(merged.modifier.contains(Modifier::BOLD | Modifier::ITALIC));
+
+        let merged = merged.merge(&Style::new().fg(Color::Green));
+        assert_eq!(merged.fg, Some(Color::Green));
+        assert_eq!(merged.bg, Some(Color::Blue));
+
+        let mut patched = base;
+        patched.patch(&over);
+        assert_eq!(patched, base.merge(&over));
+    }
+}
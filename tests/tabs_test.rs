@@ -0,0 +1,50 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use termint::{
+        buffer::Buffer,
+        enums::Color,
+        geometry::{Rect, Vec2},
+        widgets::{Tabs, Widget},
+    };
+
+    fn row(buffer: &Buffer) -> String {
+        buffer.content().iter().map(|c| c.val).collect()
+    }
+
+    /// Tests rendering tabs with highlighted selected tab
+    #[test]
+    fn tabs_render() {
+        let selected = Rc::new(Cell::new(1));
+        let tabs = Tabs::new(vec!["One", "Two", "Three"], selected)
+            .divider('|')
+            .selected_style(Color::Red);
+        assert_eq!(tabs.width(&Vec2::new(20, 1)), 13);
+        assert_eq!(tabs.height(&Vec2::new(20, 1)), 1);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 1));
+        tabs.render(&mut buffer);
+        assert_eq!(row(&buffer), "One|Two|Three  ");
+
+        let fg: Vec<_> = buffer.content().iter().map(|c| c.fg).collect();
+        assert_eq!(fg[3], Color::Default);
+        assert_eq!(fg[4..7], [Color::Red; 3]);
+        assert_eq!(fg[7], Color::Default);
+    }
+
+    /// Tests truncating tabs overflowing the rect
+    #[test]
+    fn tabs_overflow() {
+        let selected = Rc::new(Cell::new(0));
+        let tabs = Tabs::new(vec!["One", "Two", "Three"], selected)
+            .divider('|')
+            .ellipsis("…");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        tabs.render(&mut buffer);
+        assert_eq!(row(&buffer), "One|Two|T…");
+    }
+}
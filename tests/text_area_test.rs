@@ -0,0 +1,63 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, Vec2},
+        widgets::{TextArea, Widget},
+    };
+
+    fn lines(lines: &[&str]) -> Rc<RefCell<Vec<String>>> {
+        Rc::new(RefCell::new(lines.iter().map(|l| l.to_string()).collect()))
+    }
+
+    /// Tests editing lines and moving the cursor between them
+    #[test]
+    fn text_area_edit() {
+        let text = lines(&["ab", "cd"]);
+        let cursor = Rc::new(Cell::new(Vec2::new(1, 0)));
+        let area = TextArea::new(text.clone(), cursor.clone());
+
+        area.insert_newline();
+        assert_eq!(*text.borrow(), vec!["a", "b", "cd"]);
+        assert_eq!(cursor.get(), Vec2::new(0, 1));
+
+        area.backspace();
+        assert_eq!(*text.borrow(), vec!["ab", "cd"]);
+        assert_eq!(cursor.get(), Vec2::new(1, 0));
+
+        area.end();
+        area.delete();
+        assert_eq!(*text.borrow(), vec!["abcd"]);
+
+        area.move_right();
+        area.insert_char('e');
+        assert_eq!(*text.borrow(), vec!["abced"]);
+        assert_eq!(area.height(&Vec2::new(10, 10)), 1);
+    }
+
+    /// Tests scrolling to the cursor and expanding tabs
+    #[test]
+    fn text_area_scroll() {
+        let text = lines(&["one", "two", "\tx", "four"]);
+        let cursor = Rc::new(Cell::new(Vec2::new(1, 2)));
+        let area = TextArea::new(text, cursor.clone()).tab_width(2);
+        assert_eq!(area.height(&Vec2::new(10, 10)), 4);
+        assert_eq!(area.width(&Vec2::new(10, 10)), 4);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        area.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "two \n  x ");
+
+        area.move_down();
+        area.end();
+        area.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "  x \nour ");
+    }
+}
@@ -0,0 +1,80 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+    };
+
+    use termint::{
+        buffer::Buffer,
+        enums::Modifier,
+        geometry::{Rect, Vec2},
+        widgets::{TextInput, Widget},
+    };
+
+    fn input(text: &str, cursor: usize) -> TextInput {
+        TextInput::new(
+            Rc::new(RefCell::new(text.to_string())),
+            Rc::new(Cell::new(cursor)),
+        )
+    }
+
+    /// Tests editing the text and moving the cursor
+    #[test]
+    fn text_input_edit() {
+        let text = Rc::new(RefCell::new(String::from("añb")));
+        let cursor = Rc::new(Cell::new(2));
+        let input = TextInput::new(text.clone(), cursor.clone());
+
+        input.backspace();
+        input.insert('x');
+        assert_eq!(text.borrow().as_str(), "axb");
+        assert_eq!(cursor.get(), 2);
+
+        input.move_right();
+        input.move_right();
+        assert_eq!(cursor.get(), 3);
+        input.home();
+        input.move_left();
+        input.backspace();
+        assert_eq!(cursor.get(), 0);
+        input.end();
+        assert_eq!(cursor.get(), 3);
+    }
+
+    /// Tests scrolling the text to keep the cursor at the end visible
+    #[test]
+    fn text_input_scroll() {
+        let input = input("Hello world", 11);
+        assert_eq!(input.width(&Vec2::new(20, 1)), 11);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        input.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "orld ");
+        let cursor = &buffer[Vec2::new(4, 0)];
+        assert!(cursor.modifier.contains(Modifier::INVERSED));
+
+        input.home();
+        input.render(&mut buffer);
+        assert_eq!(buffer.to_plain_string(), "Hello");
+        let cursor = &buffer[Vec2::new(0, 0)];
+        assert!(cursor.modifier.contains(Modifier::INVERSED));
+    }
+
+    /// Tests reporting terminal cursor position through the buffer
+    #[test]
+    fn text_input_term_cursor() {
+        let mut buffer = Buffer::empty(Rect::new(2, 1, 10, 1));
+        input("Hello", 2).render(&mut buffer);
+        assert_eq!(buffer.cursor(), None);
+
+        input("Hello", 2).term_cursor(true).render(&mut buffer);
+        assert_eq!(buffer.cursor(), Some(Vec2::new(4, 1)));
+
+        let mut parent = Buffer::empty(Rect::new(0, 0, 20, 3));
+        parent.merge(buffer);
+        assert_eq!(parent.cursor(), Some(Vec2::new(4, 1)));
+    }
+}
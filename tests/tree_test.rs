@@ -0,0 +1,70 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use termint::{
+        buffer::Buffer,
+        geometry::{Rect, Vec2},
+        widgets::{Tree, TreeNode, TreeState, Widget},
+    };
+
+    /// Gets tree used in the tests
+    fn nodes() -> Vec<TreeNode> {
+        vec![
+            TreeNode::new("src")
+                .child(TreeNode::new("widgets").child(TreeNode::new("a.rs")))
+                .child(TreeNode::new("main.rs")),
+            TreeNode::new("tests"),
+        ]
+    }
+
+    /// Tests rendering expanded nodes with indentation guides
+    #[test]
+    fn tree_render() {
+        let state = Rc::new(RefCell::new(TreeState::new()));
+        let tree = Tree::new(nodes(), state);
+        assert_eq!(tree.height(&Vec2::new(20, 10)), 2);
+
+        tree.expand_all();
+        assert_eq!(tree.height(&Vec2::new(20, 10)), 5);
+        assert_eq!(tree.width(&Vec2::new(20, 10)), 12);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 5));
+        tree.render(&mut buffer);
+        assert_eq!(
+            buffer.to_plain_string(),
+            "▾ src       \n\
+             ├─ ▾ widgets\n\
+             │  └─ a.rs  \n\
+             └─ main.rs  \n\
+             tests       "
+        );
+    }
+
+    /// Tests that selection skips collapsed subtrees
+    #[test]
+    fn tree_selection() {
+        let state = Rc::new(RefCell::new(TreeState::selected(0)));
+        let tree = Tree::new(nodes(), state.clone()).auto_scroll();
+
+        tree.select_next();
+        assert_eq!(tree.selected_node().unwrap().label, "tests");
+        assert_eq!(state.borrow().selected, Some(4));
+
+        tree.toggle(0);
+        tree.toggle(1);
+        tree.select_prev();
+        assert_eq!(tree.selected_node().unwrap().label, "main.rs");
+
+        tree.collapse_all();
+        assert_eq!(state.borrow().selected, Some(0));
+
+        tree.expand_all();
+        state.borrow_mut().selected = Some(3);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        tree.render(&mut buffer);
+        assert_eq!(state.borrow().offset, 2);
+    }
+}
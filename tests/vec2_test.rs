@@ -0,0 +1,82 @@
+extern crate termint;
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use termint::geometry::{Vec2, Vec2Range};
+
+    /// Tests subtracting vectors
+    #[test]
+    fn vec2_sub() {
+        let a = Vec2::<usize>::new(5, 7);
+        let b = Vec2::new(2, 3);
+        assert_eq!(a - b, Vec2::new(3, 4));
+        assert_eq!(a - a, Vec2::new(0, 0));
+
+        let mut c = a;
+        c -= b;
+        assert_eq!(c, Vec2::new(3, 4));
+
+        // Signed vectors can go below zero
+        assert_eq!(Vec2::new(1i32, 2) - Vec2::new(3, 1), Vec2::new(-2, 1));
+    }
+
+    /// Tests that unsigned subtraction overflows, while saturating and
+    /// checked subtraction don't
+    #[test]
+    fn vec2_sub_overflow() {
+        let a = Vec2::new(1, 5);
+        let b = Vec2::new(2, 3);
+        assert_eq!(a.saturating_sub(b), Vec2::new(0, 2));
+        assert_eq!(a.checked_sub(b), None);
+        assert_eq!(b.checked_sub(Vec2::new(1, 1)), Some(Vec2::new(1, 2)));
+    }
+
+    /// Tests that unsigned subtraction panics on overflow in debug builds
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn vec2_sub_overflow_panics() {
+        let _ = Vec2::<usize>::new(1, 5) - Vec2::new(2, 3);
+    }
+
+    /// Tests the componentwise partial order of vectors
+    #[test]
+    fn vec2_partial_ord() {
+        let a = Vec2::new(1, 2);
+        assert_eq!(a.partial_cmp(&a), Some(Ordering::Equal));
+        assert_eq!(a.partial_cmp(&Vec2::new(1, 3)), Some(Ordering::Less));
+        assert_eq!(a.partial_cmp(&Vec2::new(2, 3)), Some(Ordering::Less));
+        assert_eq!(a.partial_cmp(&Vec2::new(0, 2)), Some(Ordering::Greater));
+        assert!(a <= Vec2::new(1, 3) && a < Vec2::new(1, 3));
+        assert!(Vec2::new(2, 3) > a);
+
+        // Incomparable vectors
+        assert_eq!(a.partial_cmp(&Vec2::new(2, 1)), None);
+    }
+
+    /// Tests componentwise comparison helpers
+    #[test]
+    fn vec2_componentwise() {
+        let a = Vec2::new(1, 2);
+        assert!(a.componentwise_le(&a));
+        assert!(!a.componentwise_lt(&a));
+        assert!(a.componentwise_le(&Vec2::new(1, 3)));
+        assert!(!a.componentwise_lt(&Vec2::new(1, 3)));
+        assert!(a.componentwise_lt(&Vec2::new(2, 3)));
+        assert!(!a.componentwise_le(&Vec2::new(2, 1)));
+    }
+
+    /// Tests vector range containment
+    #[test]
+    fn vec2_range_contains() {
+        let range = Vec2Range::new(Vec2::new(1, 1), Vec2::new(3, 4));
+        assert!(range.contains(&Vec2::new(1, 1)));
+        assert!(range.contains(&Vec2::new(1, 3)));
+        assert!(range.contains(&Vec2::new(2, 1)));
+        assert!(!range.contains(&Vec2::new(3, 1)));
+        assert!(!range.contains(&Vec2::new(0, 2)));
+        assert!(!range.contains(&Vec2::new(2, 4)));
+    }
+}